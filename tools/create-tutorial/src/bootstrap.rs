@@ -1,87 +1,193 @@
-use anyhow::{Context, Result};
+use anyhow::{Context as _, Result};
 use colored::*;
-use std::fs;
-use std::path::PathBuf;
-use std::process::Command;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
 
-/// Bootstraps the test environment with npm packages and configuration
-pub fn bootstrap_tests(slug: &str) -> Result<()> {
+use crate::context::Context;
+use crate::manifest::{Manifest, TestFramework};
+use crate::pipeline::CreateOptions;
+use crate::runner::CommandSpec;
+use crate::versions::{npm_packages, ResolvedVersions};
+
+/// Bootstraps the test environment with npm packages and configuration.
+/// Rust test frameworks bring their own `tests/Cargo.toml`, so there is
+/// nothing to install for them.
+pub fn bootstrap_tests(
+    ctx: &Context,
+    manifest: &Manifest,
+    options: &CreateOptions,
+    versions: &ResolvedVersions,
+) -> Result<()> {
     println!("\n{}", "Step 3/4: Bootstrapping test environment...".cyan());
 
-    let tutorial_dir = PathBuf::from("tutorials").join(slug);
-    let tutorial_dir_str = tutorial_dir.to_str().unwrap();
+    let slug = manifest.slug.as_str();
+    let framework = manifest.test_framework();
+    let tutorial_dir = ctx.tutorial_dir(slug);
+
+    if framework.is_rust() {
+        print_rust_success(framework);
+        return Ok(());
+    }
+
+    if options.offline {
+        // Render package.json ourselves instead of asking npm
+        write_package_json(ctx, &tutorial_dir, slug, framework, versions)?;
+
+        // Create configuration files
+        create_config_files(ctx, &tutorial_dir, framework)?;
+
+        print_offline_success();
+
+        return Ok(());
+    }
 
     // Create package.json
-    create_package_json(tutorial_dir_str, slug)?;
+    create_package_json(ctx, &tutorial_dir, slug)?;
 
     // Install dev dependencies
-    install_dev_dependencies(tutorial_dir_str)?;
+    install_dev_dependencies(ctx, &tutorial_dir, framework, versions)?;
 
     // Install dependencies
-    install_dependencies(tutorial_dir_str)?;
+    install_dependencies(ctx, &tutorial_dir, framework, versions)?;
 
     // Set npm scripts
-    set_npm_scripts(tutorial_dir_str)?;
+    set_npm_scripts(ctx, &tutorial_dir)?;
 
     // Create configuration files
-    create_config_files(&tutorial_dir)?;
+    create_config_files(ctx, &tutorial_dir, framework)?;
 
-    print_success();
+    print_success(framework);
 
     Ok(())
 }
 
-fn create_package_json(tutorial_dir_str: &str, slug: &str) -> Result<()> {
-    let package_json_path = PathBuf::from(tutorial_dir_str).join("package.json");
-    if !package_json_path.exists() {
-        Command::new("sh")
-            .arg("-c")
-            .arg(format!("cd {} && npm init -y", tutorial_dir_str))
-            .output()
+fn npm(tutorial_dir: &Path) -> CommandSpec {
+    CommandSpec::new("npm").current_dir(tutorial_dir)
+}
+
+/// package.json written by `--offline` mode
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageJson {
+    name: String,
+    version: &'static str,
+    private: bool,
+    #[serde(rename = "type")]
+    module_type: &'static str,
+    scripts: BTreeMap<&'static str, &'static str>,
+    dev_dependencies: BTreeMap<String, String>,
+    dependencies: BTreeMap<String, String>,
+}
+
+/// Renders a complete package.json for `framework`'s packages, with
+/// versions pinned from `versions.yml`
+pub fn render_package_json(slug: &str, framework: TestFramework, versions: &ResolvedVersions) -> String {
+    let mut dev_dependencies = BTreeMap::new();
+    let mut dependencies = BTreeMap::new();
+    for (package, dev) in npm_packages(framework) {
+        let target = if dev { &mut dev_dependencies } else { &mut dependencies };
+        target.insert(package.to_string(), versions.npm_version(package).to_string());
+    }
+
+    let package_json = PackageJson {
+        name: slug.to_string(),
+        version: "0.1.0",
+        private: true,
+        module_type: "module",
+        scripts: BTreeMap::from([("test", "vitest run"), ("test:watch", "vitest")]),
+        dev_dependencies,
+        dependencies,
+    };
+
+    serde_json::to_string_pretty(&package_json).expect("package.json always serializes") + "\n"
+}
+
+fn write_package_json(
+    ctx: &Context,
+    tutorial_dir: &Path,
+    slug: &str,
+    framework: TestFramework,
+    versions: &ResolvedVersions,
+) -> Result<()> {
+    let package_json_path = tutorial_dir.join("package.json");
+    if !ctx.fs.exists(&package_json_path) {
+        ctx.fs.write(&package_json_path, &render_package_json(slug, framework, versions))?;
+    }
+    Ok(())
+}
+
+fn create_package_json(ctx: &Context, tutorial_dir: &Path, slug: &str) -> Result<()> {
+    if !ctx.fs.exists(&tutorial_dir.join("package.json")) {
+        ctx.runner
+            .run_checked(&npm(tutorial_dir).args(["init", "-y"]))
             .context("Failed to run npm init")?;
 
-        Command::new("sh")
-            .arg("-c")
-            .arg(format!("cd {} && npm pkg set name={} type=module", tutorial_dir_str, slug))
-            .output()
+        ctx.runner
+            .run_checked(&npm(tutorial_dir).args(["pkg", "set", &format!("name={}", slug), "type=module"]))
             .context("Failed to set package.json fields")?;
     }
     Ok(())
 }
 
-fn install_dev_dependencies(tutorial_dir_str: &str) -> Result<()> {
-    println!("{}", "ℹ️  Installing dev dependencies (vitest, typescript, ts-node, @types/node)...".cyan());
-    Command::new("sh")
-        .arg("-c")
-        .arg(format!("cd {} && npm i -D vitest typescript ts-node @types/node", tutorial_dir_str))
-        .status()
+/// Names of `framework`'s dev or runtime dependencies
+fn package_names(framework: TestFramework, dev: bool) -> Vec<&'static str> {
+    npm_packages(framework)
+        .into_iter()
+        .filter(|(_, is_dev)| *is_dev == dev)
+        .map(|(package, _)| package)
+        .collect()
+}
+
+/// `package@version` specs for `framework`'s dev or runtime dependencies
+fn pinned_packages(framework: TestFramework, versions: &ResolvedVersions, dev: bool) -> Vec<String> {
+    package_names(framework, dev)
+        .into_iter()
+        .map(|package| format!("{}@{}", package, versions.npm_version(package)))
+        .collect()
+}
+
+fn install_dev_dependencies(
+    ctx: &Context,
+    tutorial_dir: &Path,
+    framework: TestFramework,
+    versions: &ResolvedVersions,
+) -> Result<()> {
+    let message = format!("ℹ️  Installing dev dependencies ({})...", package_names(framework, true).join(", "));
+    println!("{}", message.cyan());
+    ctx.runner
+        .run_checked(&npm(tutorial_dir).args(["i", "-D"]).args(pinned_packages(framework, versions, true)))
         .context("Failed to install dev dependencies")?;
     Ok(())
 }
 
-fn install_dependencies(tutorial_dir_str: &str) -> Result<()> {
-    println!("{}", "ℹ️  Installing dependencies (@polkadot/api, ws)...".cyan());
-    Command::new("sh")
-        .arg("-c")
-        .arg(format!("cd {} && npm i @polkadot/api ws", tutorial_dir_str))
-        .status()
+fn install_dependencies(
+    ctx: &Context,
+    tutorial_dir: &Path,
+    framework: TestFramework,
+    versions: &ResolvedVersions,
+) -> Result<()> {
+    let message = format!("ℹ️  Installing dependencies ({})...", package_names(framework, false).join(", "));
+    println!("{}", message.cyan());
+    ctx.runner
+        .run_checked(&npm(tutorial_dir).arg("i").args(pinned_packages(framework, versions, false)))
         .context("Failed to install dependencies")?;
     Ok(())
 }
 
-fn set_npm_scripts(tutorial_dir_str: &str) -> Result<()> {
-    Command::new("sh")
-        .arg("-c")
-        .arg(format!(
-            "cd {} && npm pkg set scripts.test=\"vitest run\" scripts.test:watch=\"vitest\"",
-            tutorial_dir_str
-        ))
-        .output()
+fn set_npm_scripts(ctx: &Context, tutorial_dir: &Path) -> Result<()> {
+    ctx.runner
+        .run_checked(&npm(tutorial_dir).args(["pkg", "set", "scripts.test=vitest run", "scripts.test:watch=vitest"]))
         .context("Failed to set npm scripts")?;
     Ok(())
 }
 
-fn create_config_files(tutorial_dir: &PathBuf) -> Result<()> {
+/// Writes vitest.config.ts and tsconfig.json; Rust test crates need neither
+pub(crate) fn create_config_files(ctx: &Context, tutorial_dir: &Path, framework: TestFramework) -> Result<()> {
+    if framework.is_rust() {
+        return Ok(());
+    }
+
     // Create vitest.config.ts
     let vitest_config = r#"import { defineConfig } from 'vitest/config';
 export default defineConfig({
@@ -92,7 +198,7 @@ export default defineConfig({
   },
 });
 "#;
-    fs::write(tutorial_dir.join("vitest.config.ts"), vitest_config)?;
+    ctx.fs.write(&tutorial_dir.join("vitest.config.ts"), vitest_config)?;
 
     // Create tsconfig.json
     let tsconfig_content = r#"{
@@ -108,21 +214,85 @@ export default defineConfig({
   "include": ["tests/**/*.ts"]
 }
 "#;
-    fs::write(tutorial_dir.join("tsconfig.json"), tsconfig_content)?;
+    ctx.fs.write(&tutorial_dir.join("tsconfig.json"), tsconfig_content)?;
 
     Ok(())
 }
 
-fn print_success() {
+fn print_offline_success() {
+    println!("{}", "✅ Test environment ready (offline)".green());
+    println!("{}", "  - package.json written with pinned versions".cyan());
+    println!("{}", "  - vitest.config.ts & tsconfig.json configured".cyan());
+    println!("{}", "ℹ️  Run `npm install` in the tutorial directory once you are online".cyan());
+}
+
+fn print_success(framework: TestFramework) {
     println!("{}", "✅ Test environment ready".green());
     println!("{}", "  - package.json created".cyan());
-    println!("{}", "  - vitest, typescript, @polkadot/api installed".cyan());
+    println!("{}", format!("  - {} installed", package_names(framework, false).join(", ")).cyan());
     println!("{}", "  - vitest.config.ts & tsconfig.json configured".cyan());
 }
 
+fn print_rust_success(framework: TestFramework) {
+    println!("{}", "✅ Test environment ready".green());
+    println!("{}", format!("  - {} test crate scaffolded in tests/Cargo.toml", framework).cyan());
+    println!("{}", "ℹ️  No npm packages needed; cargo fetches the test dependencies".cyan());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filesystem::MemoryFileSystem;
+    use crate::manifest::{ManifestFields, TutorialType};
+    use crate::runner::RecordingRunner;
+
+    #[test]
+    fn test_render_package_json_pins_versions() {
+        let versions = crate::versions::VersionsFile::from_yaml_str("npm:\n  vitest: \"3.0.0\"\n")
+            .unwrap()
+            .resolve("my-tutorial");
+        let package_json: serde_json::Value =
+            serde_json::from_str(&render_package_json("my-tutorial", TestFramework::Vitest, &versions)).unwrap();
+
+        assert_eq!(package_json["name"], "my-tutorial");
+        assert_eq!(package_json["type"], "module");
+        assert_eq!(package_json["scripts"]["test"], "vitest run");
+        assert_eq!(package_json["devDependencies"]["vitest"], "3.0.0");
+        assert_eq!(package_json["devDependencies"]["typescript"], "^5.9.2");
+        assert_eq!(package_json["dependencies"]["@polkadot/api"], "^16.4.6");
+        assert!(package_json["dependencies"].get("vitest").is_none());
+        assert!(package_json["dependencies"].get("polkadot-api").is_none());
+    }
+
+    #[test]
+    fn test_papi_package_json_swaps_client_library() {
+        let package_json: serde_json::Value =
+            serde_json::from_str(&render_package_json("my-tutorial", TestFramework::Papi, &Default::default()))
+                .unwrap();
+
+        assert_eq!(package_json["scripts"]["test"], "vitest run");
+        assert_eq!(package_json["devDependencies"]["vitest"], "^3.2.4");
+        assert_eq!(package_json["dependencies"]["polkadot-api"], "^1.19.2");
+        assert!(package_json["dependencies"].get("@polkadot/api").is_none());
+        assert!(package_json["dependencies"].get("ws").is_none());
+    }
+
+    #[test]
+    fn test_rust_frameworks_skip_npm() {
+        let fs = MemoryFileSystem::new();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+        let fields = ManifestFields {
+            tutorial_type: Some(TutorialType::Sdk),
+            test_framework: Some("subxt".to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+        bootstrap_tests(&ctx, &manifest, &CreateOptions::default(), &Default::default()).unwrap();
+
+        assert!(runner.commands().is_empty());
+        assert!(fs.files().is_empty());
+    }
 
     #[test]
     fn test_vitest_config_generation() {
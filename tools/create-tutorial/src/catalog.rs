@@ -0,0 +1,179 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::output::OutputFormat;
+use crate::validator::is_valid_slug;
+
+/// A tutorial discovered under `tutorials/`
+#[derive(Debug, Clone)]
+pub struct TutorialEntry {
+    pub dir: PathBuf,
+    pub manifest: Manifest,
+}
+
+/// One row of `create-tutorial list`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TutorialSummary {
+    pub name: String,
+    pub slug: String,
+    pub category: String,
+    #[serde(rename = "type")]
+    pub tutorial_type: Option<String>,
+    pub needs_node: bool,
+    pub test_framework: Option<String>,
+}
+
+impl From<&TutorialEntry> for TutorialSummary {
+    fn from(entry: &TutorialEntry) -> Self {
+        let manifest = &entry.manifest;
+        TutorialSummary {
+            name: manifest.name.clone(),
+            slug: manifest.slug.clone(),
+            category: manifest.category.clone(),
+            tutorial_type: manifest.tutorial_type.clone(),
+            needs_node: manifest.needs_node,
+            test_framework: manifest
+                .manifest
+                .as_ref()
+                .and_then(|spec| spec.tests.as_ref())
+                .and_then(|tests| tests.framework.clone()),
+        }
+    }
+}
+
+/// Finds every `tutorials/<slug>/tutorial.yml` and parses it, sorted by slug.
+/// Directories that are not valid slugs (e.g. `_archive`) are skipped.
+pub fn discover_tutorials(tutorials_dir: &Path) -> Result<Vec<TutorialEntry>> {
+    let mut entries = Vec::new();
+
+    for entry in fs::read_dir(tutorials_dir)? {
+        let dir = entry?.path();
+        let is_tutorial = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(is_valid_slug);
+        let manifest_path = dir.join(MANIFEST_FILE);
+        if !is_tutorial || !manifest_path.is_file() {
+            continue;
+        }
+
+        let manifest = Manifest::load(&manifest_path)?;
+        entries.push(TutorialEntry { dir, manifest });
+    }
+
+    entries.sort_by(|a, b| a.dir.cmp(&b.dir));
+    Ok(entries)
+}
+
+/// Prints the tutorial catalog as a table or JSON
+pub fn print_catalog(entries: &[TutorialEntry], format: OutputFormat) -> Result<()> {
+    let summaries: Vec<TutorialSummary> = entries.iter().map(TutorialSummary::from).collect();
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&summaries)?),
+        OutputFormat::Text => print!("{}", render_table(&summaries)),
+    }
+
+    Ok(())
+}
+
+fn render_table(summaries: &[TutorialSummary]) -> String {
+    let header = ["NAME", "SLUG", "CATEGORY", "TYPE", "NEEDS NODE", "TESTS"];
+    let rows: Vec<[String; 6]> = summaries
+        .iter()
+        .map(|s| {
+            [
+                s.name.clone(),
+                s.slug.clone(),
+                s.category.clone(),
+                s.tutorial_type.clone().unwrap_or_else(|| "-".to_string()),
+                if s.needs_node { "yes" } else { "no" }.to_string(),
+                s.test_framework.clone().unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = format_row(&header.map(str::to_string)) + "\n";
+    for row in &rows {
+        table += &format_row(row);
+        table += "\n";
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_tutorial(tutorials_dir: &Path, slug: &str, title: &str) {
+        let dir = tutorials_dir.join(slug);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join(MANIFEST_FILE),
+            crate::templates::generate_tutorial_yml(slug, title, crate::manifest::TutorialType::Sdk),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_discovers_tutorials_sorted_by_slug() {
+        let temp_dir = TempDir::new().unwrap();
+        write_tutorial(temp_dir.path(), "zero-to-hero", "Zero to Hero");
+        write_tutorial(temp_dir.path(), "add-nft-pallet", "Add NFT Pallet");
+        fs::create_dir_all(temp_dir.path().join("no-manifest")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("_archive")).unwrap();
+
+        let entries = discover_tutorials(temp_dir.path()).unwrap();
+        let slugs: Vec<_> = entries.iter().map(|e| e.manifest.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["add-nft-pallet", "zero-to-hero"]);
+    }
+
+    #[test]
+    fn test_fails_on_unparseable_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("broken");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), "needs_node: [").unwrap();
+
+        assert!(discover_tutorials(temp_dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_summary_and_table() {
+        let temp_dir = TempDir::new().unwrap();
+        write_tutorial(temp_dir.path(), "my-tutorial", "My Tutorial");
+        let entries = discover_tutorials(temp_dir.path()).unwrap();
+
+        let summary = TutorialSummary::from(&entries[0]);
+        assert_eq!(summary.tutorial_type.as_deref(), Some("sdk"));
+        assert_eq!(summary.test_framework.as_deref(), Some("vitest"));
+
+        let table = render_table(&[summary]);
+        let lines: Vec<_> = table.lines().collect();
+        assert!(lines[0].starts_with("NAME"));
+        assert!(lines[1].starts_with("My Tutorial  my-tutorial"));
+        assert!(lines[1].ends_with("yes         vitest"));
+    }
+}
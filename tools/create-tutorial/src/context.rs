@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+
+use crate::filesystem::FileSystem;
+use crate::runner::{CommandRunner, CommandSpec};
+
+/// Side-effect handles threaded through the creation steps, so the same
+/// pipeline can run for real, as a dry run, or against a fake in tests
+pub struct Context<'a> {
+    /// Repository root; empty means the current directory
+    pub root: PathBuf,
+    pub fs: &'a dyn FileSystem,
+    pub runner: &'a dyn CommandRunner,
+    /// Set when `fs` and `runner` only record what would happen
+    pub dry_run: bool,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(fs: &'a dyn FileSystem, runner: &'a dyn CommandRunner) -> Self {
+        Context {
+            root: PathBuf::new(),
+            fs,
+            runner,
+            dry_run: false,
+        }
+    }
+
+    pub fn with_root(mut self, root: &Path) -> Self {
+        self.root = root.to_path_buf();
+        self
+    }
+
+    pub fn as_dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
+    /// Directory of the tutorial with the given slug
+    pub fn tutorial_dir(&self, slug: &str) -> PathBuf {
+        self.root.join("tutorials").join(slug)
+    }
+
+    /// A command that runs from the repository root
+    pub fn command(&self, program: &str) -> CommandSpec {
+        let command = CommandSpec::new(program);
+        if self.root.as_os_str().is_empty() {
+            command
+        } else {
+            command.current_dir(&self.root)
+        }
+    }
+}
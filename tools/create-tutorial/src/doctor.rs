@@ -0,0 +1,280 @@
+use regex::Regex;
+
+use crate::context::Context;
+use crate::health::HealthReport;
+use crate::runner::CommandOutput;
+use crate::versions::ResolvedVersions;
+
+/// Oldest Node.js major version vitest supports
+pub const MIN_NODE_MAJOR: u32 = 18;
+
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Node tooling pinned in `versions.yml`, as (versions.yml key, binary)
+const NODE_TOOLS: &[(&str, &str)] = &[
+    ("chain_spec_builder", "chain-spec-builder"),
+    ("polkadot_omni_node", "polkadot-omni-node"),
+    ("frame_omni_bencher", "frame-omni-bencher"),
+];
+
+/// How to install `version` of a node tool, preferring `common-scripts/`
+fn install_command(binary: &str, version: &str) -> String {
+    match binary {
+        "chain-spec-builder" => format!(
+            "CHAIN_SPEC_BUILDER_VERSION={} ./common-scripts/install-chain-spec-builder.sh",
+            version
+        ),
+        "polkadot-omni-node" => format!("OMNI_NODE_VERSION={} ./common-scripts/install-omni-node.sh", version),
+        _ => format!("cargo install --locked {}@{}", binary, version),
+    }
+}
+
+/// Checks the contributor's machine for the tools tutorials need, against
+/// the versions pinned in `versions.yml`. Only runs `--version`-style
+/// queries; nothing is installed.
+pub fn diagnose(ctx: &Context, versions: &ResolvedVersions) -> HealthReport {
+    let mut report = HealthReport::default();
+    let rust = versions.rust();
+    let setup_rust = format!("RUST_VERSION={} ./common-scripts/setup-rust.sh", rust);
+
+    match version_of(ctx, "rustc", &["--version"]) {
+        None => {
+            report
+                .fail("rustc", "not found")
+                .fix(format!("install rustup from https://rustup.rs, then run {}", setup_rust));
+        }
+        Some(found) if matches_pin(rust, &found) => {
+            report.pass("rustc", found);
+        }
+        Some(found) => {
+            report
+                .warn("rustc", format!("{} is the default, versions.yml pins {}", found, rust))
+                .fix(setup_rust.clone());
+        }
+    }
+
+    check_wasm_target(ctx, rust, &setup_rust, &mut report);
+
+    match version_of(ctx, "just", &["--version"]) {
+        Some(found) => {
+            report.pass("just", found);
+        }
+        None => report.fail("just", "not found").fix("cargo install just"),
+    }
+
+    match version_of(ctx, "node", &["--version"]) {
+        None => {
+            report
+                .fail("node", "not found")
+                .fix(format!("install Node.js {} or newer from https://nodejs.org", MIN_NODE_MAJOR));
+        }
+        Some(found) => {
+            let major = found.split('.').next().and_then(|major| major.parse::<u32>().ok());
+            if major.is_some_and(|major| major >= MIN_NODE_MAJOR) {
+                report.pass("node", found);
+            } else {
+                report
+                    .fail("node", format!("{} is too old; vitest needs {} or newer", found, MIN_NODE_MAJOR))
+                    .fix(format!("install Node.js {} or newer from https://nodejs.org", MIN_NODE_MAJOR));
+            }
+        }
+    }
+
+    match version_of(ctx, "npm", &["--version"]) {
+        Some(found) => {
+            report.pass("npm", found);
+        }
+        None => report.fail("npm", "not found").fix("npm ships with Node.js; reinstall Node.js"),
+    }
+
+    for (key, binary) in NODE_TOOLS {
+        let Some(pinned) = versions.tool(key) else {
+            continue;
+        };
+        match version_of(ctx, binary, &["--version"]) {
+            None => {
+                report.fail(*binary, "not found").fix(install_command(binary, pinned));
+            }
+            Some(found) if matches_pin(pinned, &found) => {
+                report.pass(*binary, found);
+            }
+            Some(found) => {
+                report
+                    .warn(*binary, format!("{} is installed, versions.yml pins {}", found, pinned))
+                    .fix(install_command(binary, pinned));
+            }
+        }
+    }
+
+    match version_of(ctx, "zombienet", &["version"]) {
+        Some(found) => {
+            report.pass("zombienet", found);
+        }
+        None => report
+            .warn("zombienet", "not found; only needed for `just run-zombienet`")
+            .fix("npm install -g @zombienet/cli"),
+    }
+
+    report
+}
+
+/// `rustup target list --toolchain X` installs X if it is missing, so the
+/// toolchain is looked up first
+fn check_wasm_target(ctx: &Context, rust: &str, setup_rust: &str, report: &mut HealthReport) {
+    let name = "wasm32 target";
+    let toolchains = match run(ctx, "rustup", &["toolchain", "list"]) {
+        Some(output) => output.stdout,
+        None => {
+            report
+                .fail(name, "rustup not found")
+                .fix(format!("install rustup from https://rustup.rs, then run {}", setup_rust));
+            return;
+        }
+    };
+
+    let toolchain = toolchains
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .find(|toolchain| {
+            toolchain == &rust
+                || toolchain.starts_with(&format!("{}-", rust))
+                || toolchain.starts_with(&format!("{}.", rust))
+        });
+    let Some(toolchain) = toolchain else {
+        report
+            .fail(name, format!("Rust toolchain {} is not installed", rust))
+            .fix(setup_rust);
+        return;
+    };
+
+    let installed = run(ctx, "rustup", &["target", "list", "--installed", "--toolchain", toolchain])
+        .is_some_and(|output| output.stdout.lines().any(|line| line.trim() == WASM_TARGET));
+    if installed {
+        report.pass(name, format!("{} installed for {}", WASM_TARGET, rust));
+    } else {
+        report
+            .fail(name, format!("{} is not installed for {}", WASM_TARGET, rust))
+            .fix(setup_rust);
+    }
+}
+
+/// Output of a successful run, or `None` if the program is missing or fails
+fn run(ctx: &Context, program: &str, args: &[&str]) -> Option<CommandOutput> {
+    ctx.runner
+        .run(&ctx.command(program).args(args.iter().copied()))
+        .ok()
+        .filter(|output| output.success)
+}
+
+/// The first version number a program reports (e.g. "1.86.0" from
+/// "rustc 1.86.0 (05f9846f8 2025-03-31)", "20.11.0" from "v20.11.0")
+fn version_of(ctx: &Context, program: &str, args: &[&str]) -> Option<String> {
+    let output = run(ctx, program, args)?;
+    let version = Regex::new(r"\d+(?:\.\d+)+").unwrap();
+    let text = format!("{}\n{}", output.stdout, output.stderr);
+    Some(
+        version
+            .find(&text)
+            .map(|found| found.as_str().to_string())
+            .unwrap_or_else(|| text.trim().to_string()),
+    )
+}
+
+/// Whether `found` is the pinned version: "1.86" matches "1.86.0" and
+/// "1.86.2", but not "1.87.0" or "1.860.0"
+fn matches_pin(pinned: &str, found: &str) -> bool {
+    let found: Vec<&str> = found.split('.').collect();
+    let pinned: Vec<&str> = pinned.split('.').collect();
+    pinned.len() <= found.len() && pinned.iter().zip(&found).all(|(pin, part)| pin == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::RealFileSystem;
+    use crate::health::CheckStatus;
+    use crate::runner::RecordingRunner;
+    use crate::versions::VersionsFile;
+
+    fn versions() -> ResolvedVersions {
+        VersionsFile::from_yaml_str(
+            "versions:\n  rust: \"1.86\"\n  chain_spec_builder: \"10.0.0\"\n  polkadot_omni_node: \"0.5.0\"\n  frame_omni_bencher: \"0.13.0\"\n",
+        )
+        .unwrap()
+        .resolve("my-tutorial")
+    }
+
+    fn healthy_runner() -> RecordingRunner {
+        RecordingRunner::new()
+            .respond("rustc --version", CommandOutput::success("rustc 1.86.0 (05f9846f8 2025-03-31)\n"))
+            .respond(
+                "rustup toolchain list",
+                CommandOutput::success("stable-x86_64-unknown-linux-gnu (default)\n1.86-x86_64-unknown-linux-gnu\n"),
+            )
+            .respond("rustup target list", CommandOutput::success("wasm32-unknown-unknown\nx86_64-unknown-linux-gnu\n"))
+            .respond("just --version", CommandOutput::success("just 1.40.0\n"))
+            .respond("node --version", CommandOutput::success("v20.11.0\n"))
+            .respond("npm --version", CommandOutput::success("10.2.4\n"))
+            .respond("chain-spec-builder", CommandOutput::success("chain-spec-builder 10.0.0\n"))
+            .respond("polkadot-omni-node", CommandOutput::success("polkadot-omni-node 0.5.0-abc1234\n"))
+            .respond("frame-omni-bencher", CommandOutput::success("frame-omni-bencher 0.13.0\n"))
+            .respond("zombienet version", CommandOutput::success("1.3.133\n"))
+    }
+
+    fn check<'a>(report: &'a HealthReport, name: &str) -> &'a crate::health::Check {
+        report.checks.iter().find(|check| check.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_healthy_machine_passes() {
+        let runner = healthy_runner();
+        let report = diagnose(&Context::new(&RealFileSystem, &runner), &versions());
+
+        assert!(report.checks.iter().all(|check| check.status == CheckStatus::Pass), "{:?}", report);
+        assert_eq!(check(&report, "rustc").message, "1.86.0");
+        assert!(runner
+            .command_lines()
+            .contains(&"rustup target list --installed --toolchain 1.86-x86_64-unknown-linux-gnu".to_string()));
+    }
+
+    #[test]
+    fn test_problems_come_with_fixes() {
+        let runner = RecordingRunner::new()
+            .respond("rustc --version", CommandOutput::success("rustc 1.88.0 (6b00bc388 2025-06-23)\n"))
+            .respond("rustup toolchain list", CommandOutput::success("stable-x86_64-unknown-linux-gnu\n"))
+            .respond("just", CommandOutput::failure(127, "command not found"))
+            .respond("node --version", CommandOutput::success("v16.20.0\n"))
+            .respond("chain-spec-builder", CommandOutput::success("chain-spec-builder 9.0.0\n"))
+            .respond("polkadot-omni-node", CommandOutput::failure(127, "command not found"))
+            .respond("zombienet", CommandOutput::failure(127, "command not found"));
+        let report = diagnose(&Context::new(&RealFileSystem, &runner), &versions());
+
+        let rustc = check(&report, "rustc");
+        assert_eq!(rustc.status, CheckStatus::Warn);
+        assert_eq!(rustc.fix.as_deref(), Some("RUST_VERSION=1.86 ./common-scripts/setup-rust.sh"));
+        assert_eq!(check(&report, "wasm32 target").message, "Rust toolchain 1.86 is not installed");
+        assert_eq!(check(&report, "just").status, CheckStatus::Fail);
+        assert_eq!(check(&report, "node").status, CheckStatus::Fail);
+        assert_eq!(
+            check(&report, "chain-spec-builder").fix.as_deref(),
+            Some("CHAIN_SPEC_BUILDER_VERSION=10.0.0 ./common-scripts/install-chain-spec-builder.sh")
+        );
+        assert_eq!(
+            check(&report, "polkadot-omni-node").fix.as_deref(),
+            Some("OMNI_NODE_VERSION=0.5.0 ./common-scripts/install-omni-node.sh")
+        );
+        assert_eq!(check(&report, "zombienet").status, CheckStatus::Warn);
+        assert!(report.has_failures());
+        // The missing toolchain is never queried, so rustup can't install it
+        assert!(!runner.command_lines().iter().any(|line| line.starts_with("rustup target")));
+    }
+
+    #[test]
+    fn test_matches_pin() {
+        assert!(matches_pin("1.86", "1.86.0"));
+        assert!(matches_pin("0.5.0", "0.5.0"));
+        assert!(!matches_pin("1.86", "1.87.0"));
+        assert!(!matches_pin("1.86", "1.860.0"));
+        assert!(!matches_pin("10.0.0", "10.0"));
+    }
+}
@@ -0,0 +1,275 @@
+use anyhow::{Context as _, Result};
+use colored::*;
+use regex::Regex;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::output::OutputFormat;
+use crate::versions::{npm_packages, tool_for_variable, ResolvedVersions, NPM_PACKAGES};
+
+/// A version declared in a tutorial file that disagrees with `versions.yml`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Drift {
+    pub file: PathBuf,
+    /// Tool key or npm package name
+    pub key: String,
+    pub expected: String,
+    /// Declared version, or `None` when the file does not declare it at all
+    pub found: Option<String>,
+}
+
+/// Compares what a tutorial declares in package.json, rust-toolchain.toml,
+/// its justfile and `scripts/*.sh` against the effective versions. Only the
+/// npm packages of the tutorial's test framework are expected.
+pub fn check_drift(tutorial_dir: &Path, versions: &ResolvedVersions) -> Result<Vec<Drift>> {
+    let mut drift = Vec::new();
+    drift.extend(check_package_json(tutorial_dir, versions)?);
+    drift.extend(check_rust_toolchain(tutorial_dir, versions)?);
+
+    let mut script_files = vec![tutorial_dir.join("justfile")];
+    if let Ok(entries) = fs::read_dir(tutorial_dir.join("scripts")) {
+        let mut scripts: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "sh"))
+            .collect();
+        scripts.sort();
+        script_files.extend(scripts);
+    }
+    for file in script_files.iter().filter(|file| file.is_file()) {
+        drift.extend(check_script(file, versions)?);
+    }
+
+    Ok(drift)
+}
+
+fn check_package_json(tutorial_dir: &Path, versions: &ResolvedVersions) -> Result<Vec<Drift>> {
+    let path = tutorial_dir.join("package.json");
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    let package_json: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    // Client libraries of the other test frameworks aren't expected here
+    let framework = Manifest::load(&tutorial_dir.join(MANIFEST_FILE))
+        .map(|manifest| manifest.test_framework())
+        .unwrap_or_default();
+    let used = npm_packages(framework);
+    let unused = |package: &str| {
+        NPM_PACKAGES.iter().any(|(name, _)| *name == package) && !used.iter().any(|(name, _)| *name == package)
+    };
+
+    let mut drift = Vec::new();
+    for (package, expected) in versions.npm.iter().filter(|(package, _)| !unused(package)) {
+        let found = ["dependencies", "devDependencies"]
+            .iter()
+            .find_map(|section| package_json[section][package].as_str());
+        if found != Some(expected.as_str()) {
+            drift.push(Drift {
+                file: path.clone(),
+                key: package.clone(),
+                expected: expected.clone(),
+                found: found.map(str::to_string),
+            });
+        }
+    }
+    Ok(drift)
+}
+
+fn check_rust_toolchain(tutorial_dir: &Path, versions: &ResolvedVersions) -> Result<Vec<Drift>> {
+    let path = tutorial_dir.join("rust-toolchain.toml");
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(&path)?;
+    let channel_regex = Regex::new(r#"(?m)^\s*channel\s*=\s*"([^"]+)""#).unwrap();
+    let found = channel_regex.captures(&content).map(|c| c[1].to_string());
+
+    if found.as_deref() == Some(versions.rust()) {
+        return Ok(Vec::new());
+    }
+    Ok(vec![Drift {
+        file: path,
+        key: "rust".to_string(),
+        expected: versions.rust().to_string(),
+        found,
+    }])
+}
+
+/// Finds `VAR="x"` shell assignments and `export VAR := "x"` justfile
+/// assignments of known version variables
+fn check_script(path: &Path, versions: &ResolvedVersions) -> Result<Vec<Drift>> {
+    let content = fs::read_to_string(path)?;
+    let assignment_regex =
+        Regex::new(r#"(?m)^\s*(?:export\s+)?([A-Z_]+_VERSION)\s*:?=\s*["']?([^"'\s]+)"#).unwrap();
+
+    let mut drift = Vec::new();
+    for captures in assignment_regex.captures_iter(&content) {
+        let Some(tool) = tool_for_variable(&captures[1]) else {
+            continue;
+        };
+        let Some(expected) = versions.tool(tool.key) else {
+            continue;
+        };
+        let found = &captures[2];
+        if found != expected {
+            drift.push(Drift {
+                file: path.to_path_buf(),
+                key: tool.key.to_string(),
+                expected: expected.to_string(),
+                found: Some(found.to_string()),
+            });
+        }
+    }
+    Ok(drift)
+}
+
+/// Prints the effective version set, and the drift report when `drift` is given
+pub fn print_versions(
+    slug: &str,
+    versions: &ResolvedVersions,
+    drift: Option<&[Drift]>,
+    format: OutputFormat,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let tools: serde_json::Map<String, serde_json::Value> = versions
+                .tools
+                .iter()
+                .map(|(key, (version, source))| {
+                    (key.clone(), serde_json::json!({ "version": version, "source": source }))
+                })
+                .collect();
+            let mut report = serde_json::json!({
+                "slug": slug,
+                "tools": tools,
+                "npm": versions.npm,
+            });
+            if let Some(drift) = drift {
+                report["drift"] = serde_json::to_value(drift)?;
+            }
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Text => {
+            println!("{}", format!("Effective versions for {}:", slug).cyan());
+            for (key, (version, source)) in &versions.tools {
+                println!("  {:<22} {:<12} ({})", key, version, source.as_str());
+            }
+            println!("{}", "npm packages:".cyan());
+            for (package, version) in &versions.npm {
+                println!("  {:<22} {}", package, version);
+            }
+
+            match drift {
+                None => {}
+                Some([]) => println!("\n{}", "✅ No version drift found".green()),
+                Some(drift) => {
+                    eprintln!("\n{}", format!("❌ Found {} version mismatch(es):", drift.len()).red());
+                    for item in drift {
+                        eprintln!(
+                            "  - {}: {} is {} (expected {})",
+                            item.file.display(),
+                            item.key,
+                            item.found.as_deref().unwrap_or("missing"),
+                            item.expected
+                        );
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::render_package_json;
+    use crate::manifest::{ManifestFields, TestFramework, TutorialType};
+    use crate::templates::loader::{JUSTFILE, RUST_TOOLCHAIN};
+    use crate::templates::{TemplateLoader, TemplateVars};
+    use crate::versions::VersionsFile;
+    use tempfile::TempDir;
+
+    fn render(name: &str, versions: &ResolvedVersions) -> String {
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        TemplateLoader::embedded()
+            .render(name, &TemplateVars::new(&manifest, versions))
+            .unwrap()
+    }
+
+    fn resolved(yaml: &str) -> ResolvedVersions {
+        VersionsFile::from_yaml_str(yaml).unwrap().resolve("my-tutorial")
+    }
+
+    #[test]
+    fn test_generated_files_have_no_drift() {
+        let temp_dir = TempDir::new().unwrap();
+        let versions = resolved("versions:\n  rust: \"1.88\"\n");
+        fs::write(temp_dir.path().join("package.json"), render_package_json("my-tutorial", TestFramework::Vitest, &versions)).unwrap();
+        fs::write(temp_dir.path().join("rust-toolchain.toml"), render(RUST_TOOLCHAIN, &versions)).unwrap();
+        fs::write(temp_dir.path().join("justfile"), render(JUSTFILE, &versions)).unwrap();
+
+        assert_eq!(check_drift(temp_dir.path(), &versions).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_reports_drift_in_every_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let generated = resolved("");
+        fs::write(temp_dir.path().join("package.json"), render_package_json("my-tutorial", TestFramework::Vitest, &generated)).unwrap();
+        fs::write(temp_dir.path().join("rust-toolchain.toml"), render(RUST_TOOLCHAIN, &generated)).unwrap();
+        fs::create_dir(temp_dir.path().join("scripts")).unwrap();
+        fs::write(
+            temp_dir.path().join("scripts/install-chain-spec-builder.sh"),
+            "#!/bin/bash\nCHAIN_SPEC_VERSION=\"10.0.0\"\n",
+        )
+        .unwrap();
+
+        let bumped = resolved(
+            "versions:\n  rust: \"1.90\"\n  chain_spec_builder: \"11.0.0\"\nnpm:\n  vitest: \"^4.0.0\"\n",
+        );
+        let keys: Vec<_> = check_drift(temp_dir.path(), &bumped)
+            .unwrap()
+            .into_iter()
+            .map(|d| (d.key, d.found.unwrap()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("vitest".to_string(), "^3.2.4".to_string()),
+                ("rust".to_string(), "1.86".to_string()),
+                ("chain_spec_builder".to_string(), "10.0.0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_missing_npm_package() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("package.json"), r#"{"devDependencies": {}}"#).unwrap();
+
+        let drift = check_drift(temp_dir.path(), &resolved("")).unwrap();
+        assert!(drift.iter().all(|d| d.found.is_none()));
+        assert_eq!(drift.len(), 6);
+    }
+
+    #[test]
+    fn test_expects_only_the_frameworks_packages() {
+        let temp_dir = TempDir::new().unwrap();
+        let fields = ManifestFields {
+            test_framework: Some("papi".to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+        fs::write(temp_dir.path().join(MANIFEST_FILE), manifest.to_yaml().unwrap()).unwrap();
+        fs::write(temp_dir.path().join("package.json"), r#"{"devDependencies": {}}"#).unwrap();
+
+        let keys: Vec<_> = check_drift(temp_dir.path(), &resolved("")).unwrap().into_iter().map(|d| d.key).collect();
+        assert_eq!(keys, ["@types/node", "polkadot-api", "ts-node", "typescript", "vitest"]);
+    }
+}
@@ -0,0 +1,455 @@
+use anyhow::{Context as _, Result};
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File operations performed while creating a tutorial
+pub trait FileSystem {
+    fn create_dir_all(&self, path: &Path) -> Result<()>;
+    fn write(&self, path: &Path, contents: &str) -> Result<()>;
+    fn exists(&self, path: &Path) -> bool;
+    fn read_to_string(&self, path: &Path) -> Result<String>;
+    fn remove_file(&self, path: &Path) -> Result<()>;
+    fn remove_dir_all(&self, path: &Path) -> Result<()>;
+    fn is_dir(&self, path: &Path) -> bool;
+    /// Files and directories directly inside `path`, sorted. Entries are
+    /// joined onto `path`, so an empty `path` (the current directory)
+    /// gives relative paths.
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>>;
+    fn rename(&self, from: &Path, to: &Path) -> Result<()>;
+}
+
+/// Writes to the real filesystem
+pub struct RealFileSystem;
+
+impl FileSystem for RealFileSystem {
+    fn create_dir_all(&self, path: &Path) -> Result<()> {
+        fs::create_dir_all(path).with_context(|| format!("Failed to create {}", path.display()))
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> Result<()> {
+        fs::remove_dir_all(path).with_context(|| format!("Failed to remove {}", path.display()))
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.as_os_str().is_empty() || path.is_dir()
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        let readable = if path.as_os_str().is_empty() { Path::new(".") } else { path };
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(readable).with_context(|| format!("Failed to read {}", readable.display()))? {
+            entries.push(path.join(entry?.file_name()));
+        }
+        entries.sort();
+        Ok(entries)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        fs::rename(from, to).with_context(|| format!("Failed to move {} to {}", from.display(), to.display()))
+    }
+}
+
+/// A file operation recorded by `DryRunFileSystem`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileOperation {
+    CreateDir(PathBuf),
+    WriteFile {
+        path: PathBuf,
+        size: usize,
+        sha256: String,
+    },
+}
+
+/// Records file operations instead of performing them
+#[derive(Default)]
+pub struct DryRunFileSystem {
+    operations: RefCell<Vec<FileOperation>>,
+    /// Contents of the planned writes, so later steps can read them back
+    contents: RefCell<BTreeMap<PathBuf, String>>,
+}
+
+impl DryRunFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn operations(&self) -> Vec<FileOperation> {
+        self.operations.borrow().clone()
+    }
+}
+
+impl FileSystem for DryRunFileSystem {
+    fn create_dir_all(&self, path: &Path) -> Result<()> {
+        self.operations
+            .borrow_mut()
+            .push(FileOperation::CreateDir(path.to_path_buf()));
+        Ok(())
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        self.operations.borrow_mut().push(FileOperation::WriteFile {
+            path: path.to_path_buf(),
+            size: contents.len(),
+            sha256: format!("{:x}", Sha256::digest(contents.as_bytes())),
+        });
+        self.contents
+            .borrow_mut()
+            .insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+            || self.operations.borrow().iter().any(|op| match op {
+                FileOperation::CreateDir(dir) => dir.starts_with(path),
+                FileOperation::WriteFile { path: file, .. } => file == path,
+            })
+    }
+
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        match self.contents.borrow().get(path) {
+            Some(contents) => Ok(contents.clone()),
+            None => RealFileSystem.read_to_string(path),
+        }
+    }
+
+    // A dry run never creates anything, so there is nothing to remove
+    fn remove_file(&self, _path: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, _path: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        RealFileSystem.is_dir(path)
+            || self.operations.borrow().iter().any(|op| match op {
+                FileOperation::CreateDir(dir) => dir.starts_with(path),
+                FileOperation::WriteFile { path: file, .. } => file != path && file.starts_with(path),
+            })
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        let mut entries: BTreeSet<PathBuf> = if path.exists() {
+            RealFileSystem.read_dir(path)?.into_iter().collect()
+        } else {
+            BTreeSet::new()
+        };
+        for op in self.operations.borrow().iter() {
+            let planned = match op {
+                FileOperation::CreateDir(dir) => dir,
+                FileOperation::WriteFile { path: file, .. } => file,
+            };
+            entries.extend(child_of(path, planned));
+        }
+        Ok(entries.into_iter().collect())
+    }
+
+    // Nor does it move what is already there
+    fn rename(&self, _from: &Path, _to: &Path) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// The entry directly inside `dir` that `path` lies in, if any
+fn child_of(dir: &Path, path: &Path) -> Option<PathBuf> {
+    let rest = path.strip_prefix(dir).ok()?;
+    rest.components().next().map(|first| dir.join(first))
+}
+
+/// Keeps written files in memory, so generated content can be inspected
+/// without touching the disk
+#[derive(Default)]
+pub struct MemoryFileSystem {
+    files: RefCell<BTreeMap<PathBuf, String>>,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every written file and its contents, sorted by path
+    pub fn files(&self) -> BTreeMap<PathBuf, String> {
+        self.files.borrow().clone()
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn create_dir_all(&self, _path: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        self.files.borrow_mut().insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.borrow().keys().any(|file| file.starts_with(path))
+    }
+
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        self.files
+            .borrow()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("{} was not written", path.display()))
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        self.files.borrow_mut().remove(path);
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> Result<()> {
+        self.files.borrow_mut().retain(|file, _| !file.starts_with(path));
+        Ok(())
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.files.borrow().keys().any(|file| file != path && file.starts_with(path))
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        let entries: BTreeSet<PathBuf> = self.files.borrow().keys().filter_map(|file| child_of(path, file)).collect();
+        Ok(entries.into_iter().collect())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        let mut files = self.files.borrow_mut();
+        let moved: Vec<PathBuf> = files.keys().filter(|file| file.starts_with(from)).cloned().collect();
+        if moved.is_empty() {
+            anyhow::bail!("{} was not written", from.display());
+        }
+        for file in moved {
+            let contents = files.remove(&file).unwrap();
+            files.insert(to.join(file.strip_prefix(from).unwrap()), contents);
+        }
+        Ok(())
+    }
+}
+
+/// Wraps another filesystem and remembers every path it newly created,
+/// so a failed tutorial creation can be rolled back
+pub struct TrackingFileSystem<'a> {
+    inner: &'a dyn FileSystem,
+    created: RefCell<Vec<PathBuf>>,
+}
+
+impl<'a> TrackingFileSystem<'a> {
+    pub fn new(inner: &'a dyn FileSystem) -> Self {
+        TrackingFileSystem {
+            inner,
+            created: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Newly created paths, in creation order. Only the outermost new
+    /// directory is recorded; paths inside it are removed along with it.
+    pub fn created_paths(&self) -> Vec<PathBuf> {
+        self.created.borrow().clone()
+    }
+
+    fn record(&self, path: PathBuf) {
+        let mut created = self.created.borrow_mut();
+        if !created.iter().any(|parent| path.starts_with(parent)) {
+            created.push(path);
+        }
+    }
+
+    /// Removes every created path, newest first. Returns the removed paths.
+    pub fn rollback(&self) -> Result<Vec<PathBuf>> {
+        let mut removed = Vec::new();
+        for path in self.created.borrow().iter().rev() {
+            if !self.inner.exists(path) {
+                continue;
+            }
+            if self.inner.is_dir(path) {
+                self.inner.remove_dir_all(path)?;
+            } else {
+                self.inner.remove_file(path)?;
+            }
+            removed.push(path.clone());
+        }
+        self.created.borrow_mut().clear();
+        Ok(removed)
+    }
+}
+
+impl FileSystem for TrackingFileSystem<'_> {
+    fn create_dir_all(&self, path: &Path) -> Result<()> {
+        let outermost_new = path
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !self.inner.exists(dir))
+            .last()
+            .map(Path::to_path_buf);
+        self.inner.create_dir_all(path)?;
+        if let Some(dir) = outermost_new {
+            self.record(dir);
+        }
+        Ok(())
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        let is_new = !self.inner.exists(path);
+        self.inner.write(path, contents)?;
+        if is_new {
+            self.record(path.to_path_buf());
+        }
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.inner.exists(path)
+    }
+
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        self.inner.read_to_string(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        self.inner.remove_file(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> Result<()> {
+        self.inner.remove_dir_all(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.inner.is_dir(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.inner.read_dir(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.inner.rename(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dry_run_records_writes_with_hash() {
+        let fs = DryRunFileSystem::new();
+        fs.create_dir_all(Path::new("tutorials/x/tests")).unwrap();
+        fs.write(Path::new("tutorials/x/README.md"), "hello").unwrap();
+
+        assert_eq!(
+            fs.operations(),
+            vec![
+                FileOperation::CreateDir(PathBuf::from("tutorials/x/tests")),
+                FileOperation::WriteFile {
+                    path: PathBuf::from("tutorials/x/README.md"),
+                    size: 5,
+                    sha256: "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tracking_records_outermost_new_directory() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("tutorials")).unwrap();
+        let tracking = TrackingFileSystem::new(&RealFileSystem);
+
+        let tutorial_dir = temp_dir.path().join("tutorials/x");
+        tracking.create_dir_all(&tutorial_dir.join("tests")).unwrap();
+        tracking.create_dir_all(&tutorial_dir.join("scripts")).unwrap();
+        tracking.write(&tutorial_dir.join("README.md"), "hi").unwrap();
+
+        tracking.write(&temp_dir.path().join("notes.txt"), "hi").unwrap();
+
+        assert_eq!(
+            tracking.created_paths(),
+            vec![tutorial_dir, temp_dir.path().join("notes.txt")]
+        );
+    }
+
+    #[test]
+    fn test_tracking_rollback_keeps_preexisting_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let existing = temp_dir.path().join("versions.yml");
+        fs::write(&existing, "old").unwrap();
+        let tracking = TrackingFileSystem::new(&RealFileSystem);
+
+        tracking.write(&existing, "new").unwrap();
+        tracking.create_dir_all(&temp_dir.path().join("tutorials/x")).unwrap();
+        tracking.write(&temp_dir.path().join("tutorials/x/a.txt"), "a").unwrap();
+
+        let removed = tracking.rollback().unwrap();
+        assert_eq!(removed, vec![temp_dir.path().join("tutorials")]);
+        assert!(existing.exists());
+        assert!(!temp_dir.path().join("tutorials").exists());
+    }
+
+    #[test]
+    fn test_memory_keeps_contents() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("x/README.md"), "hi").unwrap();
+        fs.write(Path::new("x/tests/a.ts"), "a").unwrap();
+        assert!(fs.exists(Path::new("x/tests")));
+
+        fs.remove_dir_all(Path::new("x/tests")).unwrap();
+        assert_eq!(
+            fs.files(),
+            BTreeMap::from([(PathBuf::from("x/README.md"), "hi".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_memory_lists_and_moves_directories() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("x/README.md"), "hi").unwrap();
+        fs.write(Path::new("x/tests/a.ts"), "a").unwrap();
+        assert!(fs.is_dir(Path::new("x/tests")));
+        assert!(!fs.is_dir(Path::new("x/README.md")));
+        assert_eq!(
+            fs.read_dir(Path::new("x")).unwrap(),
+            vec![PathBuf::from("x/README.md"), PathBuf::from("x/tests")]
+        );
+
+        fs.rename(Path::new("x"), Path::new("archive/x")).unwrap();
+        assert_eq!(
+            fs.files().into_keys().collect::<Vec<_>>(),
+            vec![PathBuf::from("archive/x/README.md"), PathBuf::from("archive/x/tests/a.ts")]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_exists_sees_planned_paths() {
+        let fs = DryRunFileSystem::new();
+        assert!(!fs.exists(Path::new("tutorials/x/package.json")));
+        fs.write(Path::new("tutorials/x/package.json"), "{}").unwrap();
+        fs.create_dir_all(Path::new("tutorials/x/tests")).unwrap();
+        assert!(fs.exists(Path::new("tutorials/x/package.json")));
+        assert!(fs.exists(Path::new("tutorials/x")));
+        assert_eq!(fs.read_to_string(Path::new("tutorials/x/package.json")).unwrap(), "{}");
+    }
+}
@@ -1,39 +1,315 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use colored::*;
-use std::process::Command;
+
+use crate::context::Context;
+
+/// Branch prefix used unless `--branch-prefix` is given
+pub const DEFAULT_BRANCH_PREFIX: &str = "feat/tutorial-";
+
+/// How tutorial creation interacts with git
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitOptions {
+    /// `false` skips every git operation (`--no-git`)
+    pub enabled: bool,
+    /// The tutorial branch is `<branch_prefix><slug>`
+    pub branch_prefix: String,
+    /// Refuse to run when the working tree has uncommitted changes
+    pub require_clean: bool,
+    /// Refuse to run when HEAD is missing commits from `origin/<base>`
+    pub base_branch: Option<String>,
+    /// Commit the scaffolded files on the new branch
+    pub commit: bool,
+}
+
+impl Default for GitOptions {
+    fn default() -> Self {
+        GitOptions {
+            enabled: true,
+            branch_prefix: DEFAULT_BRANCH_PREFIX.to_string(),
+            require_clean: false,
+            base_branch: None,
+            commit: false,
+        }
+    }
+}
+
+impl GitOptions {
+    pub fn branch_name(&self, slug: &str) -> String {
+        format!("{}{}", self.branch_prefix, slug)
+    }
+
+    /// Whether any git option was asked for; the branch is then required
+    pub fn is_customized(&self) -> bool {
+        self.commit || self.require_clean || self.base_branch.is_some() || self.branch_prefix != DEFAULT_BRANCH_PREFIX
+    }
+}
+
+/// Conventional commit message for a new tutorial
+pub fn commit_message(slug: &str) -> String {
+    format!("feat(tutorial): add {}", slug)
+}
+
+/// Checks run before anything is created: a clean working tree and an
+/// up-to-date base branch, when requested
+pub fn preflight(ctx: &Context, options: &GitOptions) -> Result<()> {
+    if !options.enabled {
+        return Ok(());
+    }
+
+    if options.require_clean {
+        let status = ctx
+            .runner
+            .run_checked(&ctx.command("git").args(["status", "--porcelain"]))?;
+        if !status.stdout.trim().is_empty() {
+            bail!(
+                "Working tree has uncommitted changes; commit or stash them first:\n{}",
+                status.stdout.trim_end()
+            );
+        }
+    }
+
+    if let Some(base) = &options.base_branch {
+        ctx.runner
+            .run_checked(&ctx.command("git").args(["fetch", "origin", base]))?;
+        let upstream = format!("origin/{}", base);
+        let check = ctx
+            .runner
+            .run(&ctx.command("git").args(["merge-base", "--is-ancestor", &upstream, "HEAD"]))?;
+        match check.code {
+            Some(0) => {}
+            Some(1) => bail!("Current branch is behind {}; rebase or merge it first", upstream),
+            _ => bail!("Failed to compare HEAD with {}: {}", upstream, check.stderr.trim()),
+        }
+    }
+
+    Ok(())
+}
+
+/// A branch switch made by `create_git_branch`, kept so it can be undone
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchSwitch {
+    /// Branch checked out before the tutorial branch was created
+    pub original: Option<String>,
+    pub created: String,
+}
 
 /// Creates a new git branch for the tutorial
-/// Branch name format: feat/tutorial-{slug}
-pub fn create_git_branch(slug: &str) -> Result<()> {
+/// Branch name format: {branch_prefix}{slug}
+/// Returns the switch that was made, or `None` if git is disabled or the
+/// branch could not be created. A failure is only a warning unless a git
+/// option was requested, such as committing the scaffold.
+pub fn create_git_branch(ctx: &Context, slug: &str, options: &GitOptions) -> Result<Option<BranchSwitch>> {
+    if !options.enabled {
+        println!("{}", "Step 1/4: Skipping git branch (--no-git)".cyan());
+        return Ok(None);
+    }
     println!("{}", "Step 1/4: Creating git branch...".cyan());
 
-    let branch_name = format!("feat/tutorial-{}", slug);
-    let output = Command::new("git")
-        .args(&["checkout", "-b", &branch_name])
-        .output();
+    let original = current_branch(ctx);
+    let branch_name = options.branch_name(slug);
+    let output = ctx
+        .runner
+        .run(&ctx.command("git").args(["checkout", "-b", &branch_name]));
 
     match output {
-        Ok(output) if output.status.success() => {
-            println!("{}", format!("✅ Created branch: {}", branch_name).green());
+        Ok(output) if output.success => {
+            if ctx.dry_run {
+                println!("{}", format!("ℹ️  Would create branch: {}", branch_name).cyan());
+            } else {
+                println!("{}", format!("✅ Created branch: {}", branch_name).green());
+            }
+            Ok(Some(BranchSwitch {
+                original,
+                created: branch_name,
+            }))
         }
-        _ => {
-            eprintln!("{}", "❌ Failed to create git branch".red());
+        failure => {
+            let reason = match failure {
+                Ok(output) => output.stderr.trim().to_string(),
+                Err(err) => err.to_string(),
+            };
+            if options.is_customized() {
+                bail!("Failed to create git branch {}: {}", branch_name, reason);
+            }
+            eprintln!("{}", format!("❌ Failed to create git branch: {}", reason).red());
             eprintln!("{}", "⚠️  You may already be on a feature branch. Continue anyway.".yellow());
+            Ok(None)
         }
     }
+}
+
+/// Stages the tutorial directory and commits it with the conventional message
+pub fn commit_tutorial(ctx: &Context, slug: &str) -> Result<()> {
+    let path = format!("tutorials/{}", slug);
+    ctx.runner
+        .run_checked(&ctx.command("git").args(["add", "--", &path]))?;
+
+    let message = commit_message(slug);
+    if let Err(err) = ctx
+        .runner
+        .run_checked(&ctx.command("git").args(["commit", "-m", &message, "--", &path]))
+    {
+        // Unstage so a rollback leaves the index as it was
+        let _ = ctx
+            .runner
+            .run(&ctx.command("git").args(["reset", "-q", "--", &path]));
+        return Err(err);
+    }
 
+    println!("{}", format!("✅ Committed: {}", message).green());
+    Ok(())
+}
+
+/// Returns the currently checked out branch, or `None` on a detached HEAD
+/// or outside a repository
+pub fn current_branch(ctx: &Context) -> Option<String> {
+    ctx.runner
+        .run(&ctx.command("git").args(["symbolic-ref", "--short", "HEAD"]))
+        .ok()
+        .filter(|output| output.success)
+        .map(|output| output.stdout.trim().to_string())
+        .filter(|branch| !branch.is_empty())
+}
+
+/// Switches back to the original branch and deletes the tutorial branch
+pub fn undo_branch_switch(ctx: &Context, switch: &BranchSwitch) -> Result<()> {
+    let Some(original) = &switch.original else {
+        anyhow::bail!("original branch unknown, staying on {}", switch.created);
+    };
+    ctx.runner
+        .run_checked(&ctx.command("git").args(["checkout", original]))?;
+    ctx.runner
+        .run_checked(&ctx.command("git").args(["branch", "-D", &switch.created]))?;
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::filesystem::DryRunFileSystem;
+    use crate::runner::{CommandOutput, RecordingRunner};
 
     #[test]
     fn test_branch_name_format() {
-        // We can't easily test git operations, but we can test the branch name format
-        let slug = "my-tutorial";
-        let expected_branch = format!("feat/tutorial-{}", slug);
-        assert_eq!(expected_branch, "feat/tutorial-my-tutorial");
+        assert_eq!(GitOptions::default().branch_name("my-tutorial"), "feat/tutorial-my-tutorial");
+        let options = GitOptions {
+            branch_prefix: "docs/".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(options.branch_name("my-tutorial"), "docs/my-tutorial");
+    }
+
+    #[test]
+    fn test_preflight_refuses_dirty_tree() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new().respond("git status", CommandOutput::success(" M README.md\n"));
+        let ctx = Context::new(&fs, &runner);
+        let options = GitOptions {
+            require_clean: true,
+            ..Default::default()
+        };
+
+        let err = preflight(&ctx, &options).unwrap_err();
+        assert!(err.to_string().contains("uncommitted changes"));
+        assert!(err.to_string().contains("M README.md"));
+    }
+
+    #[test]
+    fn test_preflight_refuses_stale_base() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new().respond("git merge-base", CommandOutput::failure(1, ""));
+        let ctx = Context::new(&fs, &runner);
+        let options = GitOptions {
+            base_branch: Some("main".to_string()),
+            ..Default::default()
+        };
+
+        let err = preflight(&ctx, &options).unwrap_err();
+        assert_eq!(err.to_string(), "Current branch is behind origin/main; rebase or merge it first");
+        assert_eq!(
+            runner.command_lines(),
+            vec!["git fetch origin main", "git merge-base --is-ancestor origin/main HEAD"]
+        );
+    }
+
+    #[test]
+    fn test_disabled_git_runs_nothing() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+        let options = GitOptions {
+            enabled: false,
+            require_clean: true,
+            commit: true,
+            ..Default::default()
+        };
+
+        preflight(&ctx, &options).unwrap();
+        assert_eq!(create_git_branch(&ctx, "my-tutorial", &options).unwrap(), None);
+        assert!(runner.commands().is_empty());
+    }
+
+    #[test]
+    fn test_branch_failure_is_fatal_when_committing() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new().respond("git checkout", CommandOutput::failure(128, "already exists"));
+        let ctx = Context::new(&fs, &runner);
+        let options = GitOptions {
+            commit: true,
+            ..Default::default()
+        };
+
+        assert!(create_git_branch(&ctx, "my-tutorial", &options).is_err());
+        assert_eq!(create_git_branch(&ctx, "my-tutorial", &GitOptions::default()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_commit_tutorial_unstages_on_failure() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new().respond("git commit", CommandOutput::failure(1, "hook failed"));
+        let ctx = Context::new(&fs, &runner);
+
+        assert!(commit_tutorial(&ctx, "my-tutorial").is_err());
+        assert_eq!(
+            runner.command_lines(),
+            vec![
+                "git add -- tutorials/my-tutorial",
+                "git commit -m feat(tutorial): add my-tutorial -- tutorials/my-tutorial",
+                "git reset -q -- tutorials/my-tutorial",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_git_branch_records_original_branch() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new().respond("git symbolic-ref", CommandOutput::success("main\n"));
+        let ctx = Context::new(&fs, &runner);
+
+        let switch = create_git_branch(&ctx, "my-tutorial", &GitOptions::default()).unwrap();
+        assert_eq!(
+            switch,
+            Some(BranchSwitch {
+                original: Some("main".to_string()),
+                created: "feat/tutorial-my-tutorial".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_undo_branch_switch() {
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+        let switch = BranchSwitch {
+            original: Some("main".to_string()),
+            created: "feat/tutorial-x".to_string(),
+        };
+
+        undo_branch_switch(&ctx, &switch).unwrap();
+        assert_eq!(
+            runner.command_lines(),
+            vec!["git checkout main", "git branch -D feat/tutorial-x"]
+        );
     }
 }
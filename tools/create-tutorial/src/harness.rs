@@ -0,0 +1,869 @@
+use anyhow::{Context as _, Result};
+use colored::*;
+use serde::{Serialize, Serializer};
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::context::Context;
+use crate::interrupt;
+use crate::manifest::{Manifest, TestFramework, MANIFEST_FILE};
+use crate::runner::{BackgroundProcess, CommandOutput, CommandSpec};
+
+/// RPC port the generated e2e tests connect to
+pub const DEFAULT_RPC_PORT: u16 = 9944;
+
+/// The node's default libp2p port
+pub const DEFAULT_P2P_PORT: u16 = 30333;
+
+/// Tutorials `test-all` runs at once
+pub const DEFAULT_JOBS: usize = 2;
+
+/// How long a spawned node gets to answer RPC
+pub const DEFAULT_NODE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Where tutorials that reuse the kitchensink parachain are built
+const KITCHENSINK_DIR: &str = "kitchensink-parachain";
+
+/// Parent of each tutorial's chain spec and node log directory, relative to
+/// the build directory
+const WORK_DIR: &str = "target/create-tutorial";
+
+const DEFAULT_RELAY_CHAIN: &str = "rococo-local";
+const DEFAULT_PARA_ID: u32 = 1000;
+
+/// Lines of output kept for a phase
+const LOG_TAIL_LINES: usize = 20;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A step of a tutorial test run, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    Build,
+    ChainSpec,
+    Node,
+    Tests,
+    Teardown,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Build => "build",
+            Phase::ChainSpec => "chain-spec",
+            Phase::Node => "node",
+            Phase::Tests => "tests",
+            Phase::Teardown => "teardown",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhaseStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// How a phase went
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseResult {
+    pub phase: Phase,
+    pub status: PhaseStatus,
+    #[serde(rename = "duration_secs", serialize_with = "as_secs")]
+    pub duration: Duration,
+    /// Exit code of the phase's last command; `None` if no command ran to
+    /// completion (skipped phases, the node, teardown)
+    pub exit_code: Option<i32>,
+    /// What ran, or why the phase failed or was skipped
+    pub message: String,
+    /// Last lines of the phase's output
+    pub log_tail: String,
+}
+
+fn as_secs<S: Serializer>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+/// Outcome of a phase, before it is timed
+struct Outcome {
+    status: PhaseStatus,
+    message: String,
+    exit_code: Option<i32>,
+    log_tail: String,
+}
+
+impl Outcome {
+    fn passed(message: impl Into<String>) -> Self {
+        Outcome {
+            status: PhaseStatus::Passed,
+            message: message.into(),
+            exit_code: None,
+            log_tail: String::new(),
+        }
+    }
+
+    fn failed(message: impl Into<String>) -> Self {
+        Outcome {
+            status: PhaseStatus::Failed,
+            message: message.into(),
+            exit_code: None,
+            log_tail: String::new(),
+        }
+    }
+
+    fn skipped(message: impl Into<String>) -> Self {
+        Outcome {
+            status: PhaseStatus::Skipped,
+            message: message.into(),
+            exit_code: None,
+            log_tail: String::new(),
+        }
+    }
+
+    fn with_log(mut self, log: &str) -> Self {
+        self.log_tail = tail(log, LOG_TAIL_LINES);
+        self
+    }
+
+    /// Adds the exit code and output of the command the phase ran
+    fn with_output(mut self, output: &CommandOutput) -> Self {
+        self.exit_code = output.code;
+        self.with_log(&combined(output))
+    }
+}
+
+/// Settings for a test run
+#[derive(Debug, Clone)]
+pub struct TestOptions {
+    /// The node's RPC port, passed to the tests as `POLKADOT_WS`
+    pub rpc_port: u16,
+    pub p2p_port: u16,
+    pub node_timeout: Duration,
+}
+
+impl Default for TestOptions {
+    fn default() -> Self {
+        TestOptions {
+            rpc_port: DEFAULT_RPC_PORT,
+            p2p_port: DEFAULT_P2P_PORT,
+            node_timeout: DEFAULT_NODE_TIMEOUT,
+        }
+    }
+}
+
+/// Per-phase results of a tutorial test run
+#[derive(Debug, Default)]
+pub struct TestReport {
+    pub slug: String,
+    pub phases: Vec<PhaseResult>,
+}
+
+impl TestReport {
+    pub fn new(slug: &str) -> Self {
+        TestReport {
+            slug: slug.to_string(),
+            phases: Vec::new(),
+        }
+    }
+
+    /// Time spent in all phases
+    pub fn duration(&self) -> Duration {
+        self.phases.iter().map(|result| result.duration).sum()
+    }
+
+    /// The first phase that failed
+    pub fn failed_phase(&self) -> Option<&PhaseResult> {
+        self.phases.iter().find(|result| result.status == PhaseStatus::Failed)
+    }
+
+    pub fn phase(&self, phase: Phase) -> Option<&PhaseResult> {
+        self.phases.iter().find(|result| result.phase == phase)
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.phases.iter().any(|result| result.status == PhaseStatus::Failed)
+    }
+
+    fn record(&mut self, phase: Phase, run: impl FnOnce() -> Outcome) {
+        if self.has_failures() && phase != Phase::Teardown {
+            self.push(phase, Duration::ZERO, Outcome::skipped("an earlier phase failed"));
+        } else {
+            println!("{}", format!("▶️  {}: {}", self.slug, phase.as_str()).cyan());
+            let start = Instant::now();
+            let outcome = run();
+            self.push(phase, start.elapsed(), outcome);
+        }
+    }
+
+    fn push(&mut self, phase: Phase, duration: Duration, outcome: Outcome) {
+        self.phases.push(PhaseResult {
+            phase,
+            status: outcome.status,
+            duration,
+            exit_code: outcome.exit_code,
+            message: outcome.message,
+            log_tail: outcome.log_tail,
+        });
+    }
+
+    /// Prints one line per phase, with the output of failed phases
+    pub fn print(&self) {
+        println!("\n{}", format!("Test results for {}:", self.slug).cyan());
+        for result in &self.phases {
+            let line = format!(
+                "{:<10} {:>7.1}s  {}",
+                result.phase.as_str(),
+                result.duration.as_secs_f64(),
+                result.message
+            );
+            match result.status {
+                PhaseStatus::Passed => println!("  {}", format!("✅ {}", line).green()),
+                PhaseStatus::Skipped => println!("  {}", format!("⏭️  {}", line).dimmed()),
+                PhaseStatus::Failed => {
+                    println!("  {}", format!("❌ {}", line).red());
+                    for log_line in result.log_tail.lines() {
+                        println!("       {}", log_line.dimmed());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds a tutorial, starts a node on its runtime when `needs_node` is
+/// set, runs its tests and stops the node again, following the manifest's
+/// `build.commands`, `runtime.wasm_path`, `network` and `tests`.
+/// A failed phase skips the ones after it; teardown always runs.
+pub fn test_tutorial(ctx: &Context, slug: &str, options: &TestOptions) -> Result<TestReport> {
+    let tutorial_dir = ctx.tutorial_dir(slug);
+    let manifest_path = tutorial_dir.join(MANIFEST_FILE);
+    let manifest = Manifest::from_yaml_str(&ctx.fs.read_to_string(&manifest_path)?)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+    let build_dir = build_dir(ctx, &tutorial_dir, &manifest);
+    let spec = manifest.manifest.clone().unwrap_or_default();
+
+    let mut report = TestReport::new(slug);
+    report.record(Phase::Build, || {
+        let commands = spec.build.map(|build| build.commands).unwrap_or_default();
+        build(ctx, &build_dir, &commands)
+    });
+
+    let mut node = None;
+    if manifest.needs_node {
+        report.record(Phase::ChainSpec, || chain_spec(ctx, slug, &build_dir, &manifest));
+        report.record(Phase::Node, || {
+            let (outcome, process) = start_node(ctx, slug, &build_dir, options);
+            node = process;
+            outcome
+        });
+    } else {
+        for phase in [Phase::ChainSpec, Phase::Node] {
+            report.push(phase, Duration::ZERO, Outcome::skipped("needs_node is false"));
+        }
+    }
+
+    report.record(Phase::Tests, || run_tests(ctx, &tutorial_dir, &manifest, options));
+
+    if let Some(mut node) = node {
+        report.record(Phase::Teardown, || match node.kill() {
+            Ok(()) => Outcome::passed("stopped the node"),
+            Err(err) => Outcome::failed(format!("{:#}", err)),
+        });
+    }
+
+    Ok(report)
+}
+
+/// Where the build commands run and `runtime.wasm_path` is resolved: the
+/// project directory, or the kitchensink parachain for tutorials whose
+/// project directory only points at it (no Cargo.toml of its own)
+fn build_dir(ctx: &Context, tutorial_dir: &Path, manifest: &Manifest) -> PathBuf {
+    let project_dir = tutorial_dir.join(manifest.project_dir());
+    if manifest.template().uses_kitchensink() && !ctx.fs.exists(&project_dir.join("Cargo.toml")) {
+        return ctx.root.join(KITCHENSINK_DIR);
+    }
+    project_dir
+}
+
+fn build(ctx: &Context, build_dir: &Path, commands: &[String]) -> Outcome {
+    if commands.is_empty() {
+        return Outcome::skipped("no manifest.build.commands");
+    }
+    let mut log = String::new();
+    let mut exit_code = None;
+    for command in commands {
+        if let Err(err) = interrupt::check() {
+            return Outcome::failed(err.to_string()).with_log(&log);
+        }
+        let spec = CommandSpec::new("sh").args(["-c", command]).current_dir(build_dir);
+        match ctx.runner.run(&spec) {
+            Ok(output) => {
+                log += &combined(&output);
+                exit_code = output.code;
+                if !output.success {
+                    let mut outcome = Outcome::failed(format!("`{}` {}", command, exit_status(&output)));
+                    outcome.exit_code = exit_code;
+                    return outcome.with_log(&log);
+                }
+            }
+            Err(err) => return Outcome::failed(format!("{:#}", err)).with_log(&log),
+        }
+    }
+    let mut outcome = Outcome::passed(commands.join("; "));
+    outcome.exit_code = exit_code;
+    outcome.with_log(&log)
+}
+
+fn chain_spec(ctx: &Context, slug: &str, build_dir: &Path, manifest: &Manifest) -> Outcome {
+    let spec = manifest.manifest.clone().unwrap_or_default();
+    let Some(wasm_path) = spec.runtime.and_then(|runtime| runtime.wasm_path) else {
+        return Outcome::failed("manifest.runtime.wasm_path is not set, so there is no runtime to run");
+    };
+    if !ctx.fs.exists(&build_dir.join(&wasm_path)) {
+        return Outcome::failed(format!(
+            "runtime {} not found in {}; do the build commands produce it?",
+            wasm_path,
+            build_dir.display()
+        ));
+    }
+    if let Err(err) = ctx.fs.create_dir_all(&build_dir.join(work_dir(slug))) {
+        return Outcome::failed(format!("{:#}", err));
+    }
+
+    let network = spec.network.unwrap_or_default();
+    let relay_chain = network.relay_chain.unwrap_or_else(|| DEFAULT_RELAY_CHAIN.to_string());
+    let para_id = network.para_id.unwrap_or(DEFAULT_PARA_ID).to_string();
+    let command = CommandSpec::new("chain-spec-builder")
+        .args(["-c", &chain_spec_path(slug)])
+        .args(["create", "-t", "development", "--relay-chain", &relay_chain, "--para-id", &para_id])
+        .args(["--runtime", &wasm_path, "named-preset", "development"])
+        .current_dir(build_dir);
+    match ctx.runner.run(&command) {
+        Ok(output) if output.success => Outcome::passed(format!(
+            "{} for {} (para {})",
+            chain_spec_path(slug),
+            relay_chain,
+            para_id
+        ))
+        .with_output(&output),
+        Ok(output) => Outcome::failed(format!("chain-spec-builder {}", exit_status(&output))).with_output(&output),
+        Err(err) => Outcome::failed(format!("{:#}", err)),
+    }
+}
+
+/// Per-tutorial, so that tutorials sharing a build directory can run at once
+fn work_dir(slug: &str) -> String {
+    format!("{}/{}", WORK_DIR, slug)
+}
+
+fn chain_spec_path(slug: &str) -> String {
+    format!("{}/chain_spec.json", work_dir(slug))
+}
+
+/// Spawns the node and waits for its RPC to answer. The process is handed
+/// back even when it never came up, so that teardown can stop it.
+fn start_node(
+    ctx: &Context,
+    slug: &str,
+    build_dir: &Path,
+    options: &TestOptions,
+) -> (Outcome, Option<Box<dyn BackgroundProcess>>) {
+    let port = options.rpc_port;
+    if let Some(busy) = [port, options.p2p_port].into_iter().find(|port| port_in_use(*port)) {
+        return (
+            Outcome::failed(format!("port {} is already in use; stop whatever is running there", busy)),
+            None,
+        );
+    }
+
+    let log = build_dir.join(work_dir(slug)).join("node.log");
+    let command = CommandSpec::new("polkadot-omni-node")
+        .args(["--chain", &chain_spec_path(slug), "--dev", "--rpc-port", &port.to_string()])
+        .args(["--port", &options.p2p_port.to_string(), "--no-prometheus"])
+        .args(["--rpc-cors", "all", "--rpc-methods", "unsafe"])
+        .current_dir(build_dir);
+    let mut process = match ctx.runner.spawn(&command, &log) {
+        Ok(process) => process,
+        Err(err) => return (Outcome::failed(format!("{:#}", err)), None),
+    };
+
+    let node_log = || std::fs::read_to_string(&log).unwrap_or_default();
+    let start = Instant::now();
+    let outcome = loop {
+        if node_ready(port) {
+            break Outcome::passed(format!("RPC up on port {}", port)).with_log(&node_log());
+        }
+        if !process.is_running() {
+            break Outcome::failed(format!("node exited before its RPC came up (log: {})", log.display()))
+                .with_log(&node_log());
+        }
+        if start.elapsed() >= options.node_timeout {
+            break Outcome::failed(format!(
+                "no RPC response on port {} after {}s (log: {})",
+                port,
+                options.node_timeout.as_secs(),
+                log.display()
+            ))
+            .with_log(&node_log());
+        }
+        if let Err(err) = interrupt::check() {
+            break Outcome::failed(err.to_string());
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    (outcome, Some(process))
+}
+
+fn run_tests(ctx: &Context, tutorial_dir: &Path, manifest: &Manifest, options: &TestOptions) -> Outcome {
+    let command = match test_command(tutorial_dir, manifest, options) {
+        Ok(command) => command,
+        Err(message) => return Outcome::failed(message),
+    };
+
+    match ctx.runner.run(&command) {
+        Ok(output) if output.success => Outcome::passed(command_line(&command)).with_output(&output),
+        Ok(output) => {
+            Outcome::failed(format!("`{}` {}", command_line(&command), exit_status(&output))).with_output(&output)
+        }
+        Err(err) => Outcome::failed(format!("{:#}", err)),
+    }
+}
+
+/// The command running the manifest's test files, pointed at the node
+/// through `POLKADOT_WS`. `REQUIRE_NODE=1` makes the generated tests fail
+/// rather than skip if they can't reach the node we started.
+fn test_command(tutorial_dir: &Path, manifest: &Manifest, options: &TestOptions) -> Result<CommandSpec, String> {
+    let tests = manifest
+        .manifest
+        .as_ref()
+        .and_then(|spec| spec.tests.clone())
+        .unwrap_or_default();
+    let name = tests.framework.as_deref().unwrap_or("vitest");
+    let framework: TestFramework = name
+        .parse()
+        .map_err(|_| format!("don't know how to run {} tests", name))?;
+    let cargo_test = CommandSpec::new("cargo").args(["test", "--manifest-path", "tests/Cargo.toml"]);
+    let command = match framework {
+        TestFramework::Vitest | TestFramework::Papi => {
+            CommandSpec::new("npx").args(["vitest", "run"]).args(tests.files.iter().cloned())
+        }
+        TestFramework::Subxt => cargo_test,
+        // The runtime is linked natively, so its wasm blob isn't needed
+        TestFramework::Runtime => cargo_test.env("SKIP_WASM_BUILD", "1"),
+    };
+
+    let command = command.current_dir(tutorial_dir);
+    if !manifest.needs_node {
+        return Ok(command);
+    }
+    Ok(command
+        .env("POLKADOT_WS", format!("ws://127.0.0.1:{}", options.rpc_port))
+        .env("REQUIRE_NODE", "1"))
+}
+
+/// Hands out RPC and P2P ports that are free on this machine and unique
+/// within a `test-all` run, counting up from the defaults
+pub struct PortAllocator {
+    next: Mutex<(u16, u16)>,
+}
+
+impl PortAllocator {
+    pub fn new(rpc_port: u16, p2p_port: u16) -> Self {
+        PortAllocator {
+            next: Mutex::new((rpc_port, p2p_port)),
+        }
+    }
+
+    /// The next (RPC, P2P) port pair
+    pub fn allocate(&self) -> (u16, u16) {
+        fn next_free(port: &mut u16) -> u16 {
+            loop {
+                let candidate = *port;
+                *port = port.wrapping_add(1);
+                if !port_in_use(candidate) {
+                    return candidate;
+                }
+            }
+        }
+
+        let mut next = self.next.lock().unwrap();
+        let rpc_port = next_free(&mut next.0);
+        let p2p_port = next_free(&mut next.1);
+        (rpc_port, p2p_port)
+    }
+}
+
+/// Runs `test` for every slug, at most `jobs` at a time, each with its own
+/// RPC and P2P ports starting from those in `options`. Results come back
+/// in the order of `slugs`.
+pub fn test_all<F>(slugs: &[String], jobs: usize, options: &TestOptions, test: F) -> Vec<Result<TestReport>>
+where
+    F: Fn(&str, &TestOptions) -> Result<TestReport> + Sync,
+{
+    let ports = PortAllocator::new(options.rpc_port, options.p2p_port);
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<TestReport>>>> = Mutex::new(slugs.iter().map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, slugs.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(slug) = slugs.get(i) else {
+                    break;
+                };
+                let (rpc_port, p2p_port) = ports.allocate();
+                let options = TestOptions {
+                    rpc_port,
+                    p2p_port,
+                    ..options.clone()
+                };
+                let result = test(slug, &options);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("every slug is tested"))
+        .collect()
+}
+
+/// Whether something accepts connections on the local port
+fn port_in_use(port: u16) -> bool {
+    TcpStream::connect_timeout(&local(port), Duration::from_millis(200)).is_ok()
+}
+
+/// Whether a node on the local port answers a `system_health` JSON-RPC call
+fn node_ready(port: u16) -> bool {
+    let probe = || -> std::io::Result<String> {
+        let mut stream = TcpStream::connect_timeout(&local(port), Duration::from_secs(1))?;
+        stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+        stream.set_write_timeout(Some(Duration::from_secs(2)))?;
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"system_health","params":[]}"#;
+        let request = format!(
+            "POST / HTTP/1.1\r\nHost: 127.0.0.1:{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            port,
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes())?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        Ok(response)
+    };
+    probe().is_ok_and(|response| response.starts_with("HTTP/1.1 200") && response.contains("\"result\""))
+}
+
+fn local(port: u16) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::LOCALHOST, port))
+}
+
+fn command_line(command: &CommandSpec) -> String {
+    std::iter::once(command.program.as_str())
+        .chain(command.args.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn combined(output: &CommandOutput) -> String {
+    format!("{}{}", output.stdout, output.stderr)
+}
+
+fn exit_status(output: &CommandOutput) -> String {
+    match output.code {
+        Some(code) => format!("failed with exit code {}", code),
+        None => "was killed by a signal".to_string(),
+    }
+}
+
+/// The last `lines` lines of `text`
+fn tail(text: &str, lines: usize) -> String {
+    let all: Vec<&str> = text.lines().collect();
+    all[all.len().saturating_sub(lines)..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::RealFileSystem;
+    use crate::manifest::ManifestFields;
+    use crate::runner::RecordingRunner;
+    use std::fs;
+    use std::net::TcpListener;
+    use tempfile::TempDir;
+
+    fn setup(needs_node: bool) -> TempDir {
+        let root = TempDir::new().unwrap();
+        let tutorial_dir = root.path().join("tutorials/my-tutorial");
+        fs::create_dir_all(tutorial_dir.join("my-tutorial-code")).unwrap();
+        let mut manifest = Manifest::new("my-tutorial", "My Tutorial", Default::default());
+        manifest.needs_node = needs_node;
+        fs::write(tutorial_dir.join(MANIFEST_FILE), manifest.to_yaml().unwrap()).unwrap();
+        root
+    }
+
+    /// A local port nothing listens on
+    fn free_port() -> u16 {
+        TcpListener::bind(local(0)).unwrap().local_addr().unwrap().port()
+    }
+
+    fn statuses(report: &TestReport) -> Vec<(&'static str, PhaseStatus)> {
+        report.phases.iter().map(|result| (result.phase.as_str(), result.status)).collect()
+    }
+
+    #[test]
+    fn test_without_node_builds_then_tests() {
+        let root = setup(false);
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root.path());
+
+        let report = test_tutorial(&ctx, "my-tutorial", &TestOptions::default()).unwrap();
+
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("build", PhaseStatus::Passed),
+                ("chain-spec", PhaseStatus::Skipped),
+                ("node", PhaseStatus::Skipped),
+                ("tests", PhaseStatus::Passed),
+            ]
+        );
+        let commands = runner.commands();
+        assert_eq!(commands[0].to_string(), format!("(cd {}/kitchensink-parachain) sh -c 'cargo build --release'", root.path().display()));
+        assert_eq!(
+            runner.command_lines()[1],
+            "npx vitest run tests/my-tutorial-e2e.test.ts"
+        );
+        assert_eq!(commands[1].cwd.as_deref(), Some(root.path().join("tutorials/my-tutorial").as_path()));
+    }
+
+    #[test]
+    fn test_failed_build_skips_the_rest() {
+        let root = setup(true);
+        let runner = RecordingRunner::new().respond("sh", CommandOutput::failure(101, "error[E0425]: cannot find value\n"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root.path());
+
+        let report = test_tutorial(&ctx, "my-tutorial", &TestOptions::default()).unwrap();
+
+        assert!(report.has_failures());
+        let build = report.phase(Phase::Build).unwrap();
+        assert_eq!(build.message, "`cargo build --release` failed with exit code 101");
+        assert_eq!(build.exit_code, Some(101));
+        assert_eq!(build.log_tail, "error[E0425]: cannot find value");
+        assert_eq!(report.phase(Phase::Tests).unwrap().status, PhaseStatus::Skipped);
+        assert!(report.phase(Phase::Teardown).is_none());
+        assert_eq!(runner.commands().len(), 1);
+    }
+
+    #[test]
+    fn test_missing_runtime_fails_chain_spec() {
+        let root = setup(true);
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root.path());
+
+        let report = test_tutorial(&ctx, "my-tutorial", &TestOptions::default()).unwrap();
+
+        let chain_spec = report.phase(Phase::ChainSpec).unwrap();
+        assert_eq!(chain_spec.status, PhaseStatus::Failed);
+        assert!(chain_spec.message.starts_with("runtime ./target/release/wbuild/"), "{}", chain_spec.message);
+        assert_eq!(report.phase(Phase::Node).unwrap().status, PhaseStatus::Skipped);
+    }
+
+    #[test]
+    fn test_node_is_torn_down_when_it_never_answers() {
+        let root = setup(true);
+        let wasm = root.path().join(KITCHENSINK_DIR).join(crate::manifest::KITCHENSINK_WASM_PATH);
+        fs::create_dir_all(wasm.parent().unwrap()).unwrap();
+        fs::write(&wasm, "wasm").unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root.path());
+        let options = TestOptions {
+            rpc_port: free_port(),
+            p2p_port: free_port(),
+            node_timeout: Duration::ZERO,
+        };
+
+        let report = test_tutorial(&ctx, "my-tutorial", &options).unwrap();
+
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("build", PhaseStatus::Passed),
+                ("chain-spec", PhaseStatus::Passed),
+                ("node", PhaseStatus::Failed),
+                ("tests", PhaseStatus::Skipped),
+                ("teardown", PhaseStatus::Passed),
+            ]
+        );
+        assert!(report.phase(Phase::Node).unwrap().message.starts_with("no RPC response on port"));
+        assert_eq!(runner.running_processes(), 0);
+        let lines = runner.command_lines();
+        assert_eq!(
+            lines[1],
+            "chain-spec-builder -c target/create-tutorial/my-tutorial/chain_spec.json create -t development \
+             --relay-chain rococo-local --para-id 1000 --runtime ./target/release/wbuild/parachain-template-runtime/\
+             parachain_template_runtime.compact.compressed.wasm named-preset development"
+        );
+        assert_eq!(
+            lines[2],
+            format!(
+                "polkadot-omni-node --chain target/create-tutorial/my-tutorial/chain_spec.json --dev --rpc-port {} \
+                 --port {} --no-prometheus --rpc-cors all --rpc-methods unsafe",
+                options.rpc_port,
+                options.p2p_port
+            )
+        );
+    }
+
+    #[test]
+    fn test_node_that_exits_early_fails() {
+        let root = setup(true);
+        let wasm = root.path().join(KITCHENSINK_DIR).join(crate::manifest::KITCHENSINK_WASM_PATH);
+        fs::create_dir_all(wasm.parent().unwrap()).unwrap();
+        fs::write(&wasm, "wasm").unwrap();
+        let runner = RecordingRunner::new().respond("polkadot-omni-node", CommandOutput::failure(1, ""));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root.path());
+        let options = TestOptions {
+            rpc_port: free_port(),
+            p2p_port: free_port(),
+            ..Default::default()
+        };
+
+        let report = test_tutorial(&ctx, "my-tutorial", &options).unwrap();
+
+        let node = report.phase(Phase::Node).unwrap();
+        assert!(node.message.starts_with("node exited before its RPC came up"), "{}", node.message);
+        assert_eq!(report.phase(Phase::Teardown).unwrap().status, PhaseStatus::Passed);
+    }
+
+    #[test]
+    fn test_tests_get_the_node_endpoint() {
+        let mut manifest = Manifest::new("my-tutorial", "My Tutorial", Default::default());
+        let options = TestOptions {
+            rpc_port: 9951,
+            ..Default::default()
+        };
+        let command = test_command(Path::new("t"), &manifest, &options).unwrap();
+        assert_eq!(
+            command.envs,
+            vec![
+                ("POLKADOT_WS".to_string(), "ws://127.0.0.1:9951".to_string()),
+                ("REQUIRE_NODE".to_string(), "1".to_string()),
+            ]
+        );
+
+        manifest.needs_node = false;
+        assert!(test_command(Path::new("t"), &manifest, &options).unwrap().envs.is_empty());
+    }
+
+    #[test]
+    fn test_test_command_follows_framework() {
+        let command = |framework: &str| {
+            let fields = ManifestFields {
+                test_framework: Some(framework.to_string()),
+                ..Default::default()
+            };
+            let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+            test_command(Path::new("t"), &manifest, &TestOptions::default()).map(|command| command.to_string())
+        };
+
+        assert_eq!(
+            command("papi").unwrap(),
+            "(cd t) POLKADOT_WS=ws://127.0.0.1:9944 REQUIRE_NODE=1 npx vitest run tests/my-tutorial-e2e.test.ts"
+        );
+        assert_eq!(
+            command("subxt").unwrap(),
+            "(cd t) POLKADOT_WS=ws://127.0.0.1:9944 REQUIRE_NODE=1 cargo test --manifest-path tests/Cargo.toml"
+        );
+        assert_eq!(command("runtime").unwrap(), "(cd t) SKIP_WASM_BUILD=1 cargo test --manifest-path tests/Cargo.toml");
+
+        let mut manifest = Manifest::new("my-tutorial", "My Tutorial", Default::default());
+        manifest.manifest.as_mut().unwrap().tests.as_mut().unwrap().framework = Some("jest".to_string());
+        assert_eq!(
+            test_command(Path::new("t"), &manifest, &TestOptions::default()).unwrap_err(),
+            "don't know how to run jest tests"
+        );
+    }
+
+    #[test]
+    fn test_allocated_ports_are_unique_and_free() {
+        let busy = TcpListener::bind(local(0)).unwrap();
+        let busy_port = busy.local_addr().unwrap().port();
+        let ports = PortAllocator::new(busy_port, busy_port);
+
+        let (rpc, p2p) = ports.allocate();
+        let (next_rpc, next_p2p) = ports.allocate();
+        assert!(rpc > busy_port && p2p > busy_port);
+        assert!(next_rpc > rpc && next_p2p > p2p);
+    }
+
+    #[test]
+    fn test_all_runs_in_parallel_up_to_jobs() {
+        let slugs: Vec<String> = ["a", "b", "c", "d", "e"].iter().map(|s| s.to_string()).collect();
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+
+        let results = test_all(&slugs, 2, &TestOptions::default(), |slug, options| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            most.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            running.fetch_sub(1, Ordering::SeqCst);
+            if slug == "c" {
+                anyhow::bail!("broken manifest");
+            }
+            let mut report = TestReport::new(slug);
+            report.push(Phase::Build, Duration::ZERO, Outcome::passed(options.rpc_port.to_string()));
+            Ok(report)
+        });
+
+        assert_eq!(most.load(Ordering::SeqCst), 2);
+        assert_eq!(results[2].as_ref().unwrap_err().to_string(), "broken manifest");
+        let reports: Vec<&TestReport> = results.iter().filter_map(|result| result.as_ref().ok()).collect();
+        assert_eq!(reports.iter().map(|report| report.slug.as_str()).collect::<Vec<_>>(), vec!["a", "b", "d", "e"]);
+        let mut ports: Vec<&str> = reports.iter().map(|report| report.phases[0].message.as_str()).collect();
+        ports.sort();
+        ports.dedup();
+        assert_eq!(ports.len(), 4);
+    }
+
+    #[test]
+    fn test_node_ready_calls_system_health() {
+        let listener = TcpListener::bind(local(0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with('}') {
+                let read = stream.read(&mut buffer).unwrap();
+                request += &String::from_utf8_lossy(&buffer[..read]);
+            }
+            let body = r#"{"jsonrpc":"2.0","id":1,"result":{"peers":0,"isSyncing":false,"shouldHavePeers":false}}"#;
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        assert!(node_ready(port));
+        assert!(server.join().unwrap().contains("\"method\":\"system_health\""));
+        assert!(!node_ready(free_port()));
+    }
+
+    #[test]
+    fn test_tail_keeps_last_lines() {
+        assert_eq!(tail("a\nb\nc\n", 2), "b\nc");
+        assert_eq!(tail("a", 5), "a");
+        assert_eq!(tail("", 5), "");
+    }
+}
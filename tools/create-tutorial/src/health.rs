@@ -0,0 +1,422 @@
+use anyhow::Result;
+use colored::*;
+use regex::Regex;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::filesystem::FileSystem;
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::output::OutputFormat;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    /// Works, but something should be looked at
+    Warn,
+    Fail,
+}
+
+/// One named check and what it found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Check {
+    /// Stable name, e.g. "package.json scripts"
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+    /// What to do about a warning or failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<String>,
+}
+
+impl Check {
+    pub fn fix(&mut self, fix: impl Into<String>) {
+        self.fix = Some(fix.into());
+    }
+}
+
+/// Results of a set of checks, in the order they ran
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    pub checks: Vec<Check>,
+}
+
+impl HealthReport {
+    /// Records a check; the returned reference can attach a fix
+    pub fn add(&mut self, name: impl Into<String>, status: CheckStatus, message: impl Into<String>) -> &mut Check {
+        self.checks.push(Check {
+            name: name.into(),
+            status,
+            message: message.into(),
+            fix: None,
+        });
+        self.checks.last_mut().unwrap()
+    }
+
+    pub fn pass(&mut self, name: impl Into<String>, message: impl Into<String>) -> &mut Check {
+        self.add(name, CheckStatus::Pass, message)
+    }
+
+    pub fn warn(&mut self, name: impl Into<String>, message: impl Into<String>) -> &mut Check {
+        self.add(name, CheckStatus::Warn, message)
+    }
+
+    pub fn fail(&mut self, name: impl Into<String>, message: impl Into<String>) -> &mut Check {
+        self.add(name, CheckStatus::Fail, message)
+    }
+
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|check| check.status == status).count()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.count(CheckStatus::Fail) > 0
+    }
+
+    /// Prints every check and a summary line, or the whole report as JSON
+    /// under `subject` (e.g. the tutorial directory)
+    pub fn print(&self, subject: &str, format: OutputFormat) -> Result<()> {
+        match format {
+            OutputFormat::Json => {
+                let report = serde_json::json!({
+                    "subject": subject,
+                    "healthy": !self.has_failures(),
+                    "checks": self.checks,
+                });
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            }
+            OutputFormat::Text => {
+                for check in &self.checks {
+                    let line = match check.status {
+                        CheckStatus::Pass => format!("✅ {}: {}", check.name, check.message).green(),
+                        CheckStatus::Warn => format!("⚠️  {}: {}", check.name, check.message).yellow(),
+                        CheckStatus::Fail => format!("❌ {}: {}", check.name, check.message).red(),
+                    };
+                    println!("  {}", line);
+                    if let Some(fix) = &check.fix {
+                        println!("     {}", format!("ℹ️  Fix: {}", fix).cyan());
+                    }
+                }
+
+                let failed = self.count(CheckStatus::Fail);
+                let warned = self.count(CheckStatus::Warn);
+                if failed > 0 {
+                    eprintln!("{}", format!("❌ {} check(s) failed for {}", failed, subject).red());
+                } else if warned > 0 {
+                    println!("{}", format!("⚠️  All checks passed with {} warning(s)", warned).yellow());
+                } else {
+                    println!("{}", format!("✅ All {} checks passed", self.checks.len()).green());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Files every scaffolded tutorial has; a missing required one fails. The
+/// npm files are only expected when the tests are TypeScript.
+const SCAFFOLD_FILES: &[(&str, bool)] = &[
+    ("README.md", true),
+    ("justfile", true),
+    (MANIFEST_FILE, true),
+    ("package.json", true),
+    ("vitest.config.ts", true),
+    ("tsconfig.json", true),
+    ("rust-toolchain.toml", false),
+    (".gitignore", false),
+];
+
+/// Files of the npm test project, replaced by [`RUST_TEST_FILES`] for the
+/// Rust test frameworks
+const NPM_TEST_FILES: &[&str] = &["package.json", "vitest.config.ts", "tsconfig.json"];
+
+const RUST_TEST_FILES: &[&str] = &["tests/Cargo.toml"];
+
+/// Checks a tutorial's setup: the scaffolded files and directories, its
+/// package.json scripts, the vitest include globs, installed node modules
+/// and the manifest. Reads go through `fs`, so a dry run sees planned files.
+pub fn check_tutorial(fs: &dyn FileSystem, tutorial_dir: &Path) -> HealthReport {
+    let mut report = HealthReport::default();
+    let framework = fs
+        .read_to_string(&tutorial_dir.join(MANIFEST_FILE))
+        .and_then(|content| Manifest::from_yaml_str(&content))
+        .map(|manifest| manifest.test_framework())
+        .unwrap_or_default();
+
+    let rust_files = RUST_TEST_FILES.iter().filter(|_| framework.is_rust()).map(|file| (*file, true));
+    let files = SCAFFOLD_FILES
+        .iter()
+        .copied()
+        .filter(|(file, _)| !(framework.is_rust() && NPM_TEST_FILES.contains(file)))
+        .chain(rust_files);
+    for (file, required) in files {
+        if fs.exists(&tutorial_dir.join(file)) {
+            report.pass(file, "present");
+        } else if required {
+            report.fail(file, "missing");
+        } else {
+            report.warn(file, "missing");
+        }
+    }
+
+    let manifest = check_manifest(fs, tutorial_dir, &mut report);
+    for dir in ["tests".to_string(), "scripts".to_string()]
+        .into_iter()
+        .chain(manifest.as_ref().map(Manifest::project_dir))
+    {
+        if fs.exists(&tutorial_dir.join(&dir)) {
+            report.pass(format!("{}/", dir), "present");
+        } else {
+            report.fail(format!("{}/", dir), "missing");
+        }
+    }
+
+    // Rust test crates have no npm project to check
+    if framework.is_rust() {
+        return report;
+    }
+
+    check_package_scripts(fs, tutorial_dir, &mut report);
+
+    let test_files = manifest
+        .as_ref()
+        .and_then(|m| m.manifest.as_ref())
+        .and_then(|spec| spec.tests.as_ref())
+        .map(|tests| tests.files.clone())
+        .unwrap_or_default();
+    check_vitest_include(fs, tutorial_dir, &test_files, &mut report);
+
+    if fs.exists(&tutorial_dir.join("node_modules").join("vitest")) {
+        report.pass("node_modules", "vitest is installed");
+    } else {
+        report.warn(
+            "node_modules",
+            format!("dependencies are not installed; run `npm install` in {}", tutorial_dir.display()),
+        );
+    }
+
+    report
+}
+
+fn check_manifest(fs: &dyn FileSystem, tutorial_dir: &Path, report: &mut HealthReport) -> Option<Manifest> {
+    let name = "manifest";
+    let path = tutorial_dir.join(MANIFEST_FILE);
+    let manifest = match fs.read_to_string(&path).and_then(|content| Manifest::from_yaml_str(&content)) {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            report.fail(name, format!("{:#}", err));
+            return None;
+        }
+    };
+
+    let violations = manifest.validate(fs, tutorial_dir);
+    if violations.is_empty() {
+        report.pass(name, "valid");
+    } else {
+        let problems: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        report.fail(name, problems.join("; "));
+    }
+    Some(manifest)
+}
+
+fn check_package_scripts(fs: &dyn FileSystem, tutorial_dir: &Path, report: &mut HealthReport) {
+    let name = "package.json scripts";
+    let Ok(content) = fs.read_to_string(&tutorial_dir.join("package.json")) else {
+        return;
+    };
+    let package: serde_json::Value = match serde_json::from_str(&content) {
+        Ok(package) => package,
+        Err(err) => {
+            report.fail(name, format!("package.json is not valid JSON: {}", err));
+            return;
+        }
+    };
+
+    let script = |key: &str| package.pointer(&format!("/scripts/{}", key)).and_then(|v| v.as_str());
+    match (script("test"), script("test:watch")) {
+        (None, _) => report.fail(name, "no \"test\" script"),
+        (Some(test), _) if !test.contains("vitest") => {
+            report.warn(name, format!("\"test\" runs `{}` instead of vitest", test))
+        }
+        (Some(_), None) => report.warn(name, "no \"test:watch\" script"),
+        (Some(_), Some(_)) => report.pass(name, "test and test:watch run vitest"),
+    };
+}
+
+fn check_vitest_include(fs: &dyn FileSystem, tutorial_dir: &Path, test_files: &[String], report: &mut HealthReport) {
+    let name = "vitest include";
+    let Ok(config) = fs.read_to_string(&tutorial_dir.join("vitest.config.ts")) else {
+        return;
+    };
+
+    let include = Regex::new(r"include:\s*\[([^\]]*)\]").unwrap();
+    let Some(globs) = include.captures(&config) else {
+        report.warn(name, "no include globs; vitest's defaults apply");
+        return;
+    };
+    let quoted = Regex::new(r#"['"`]([^'"`]+)['"`]"#).unwrap();
+    let globs: Vec<Regex> = quoted
+        .captures_iter(&globs[1])
+        .map(|glob| glob_to_regex(&glob[1]))
+        .collect();
+
+    let missed: Vec<&str> = test_files
+        .iter()
+        .map(String::as_str)
+        .filter(|file| !globs.iter().any(|glob| glob.is_match(file)))
+        .collect();
+    if globs.is_empty() {
+        report.fail(name, "include is empty, so no tests run");
+    } else if !missed.is_empty() {
+        report.fail(name, format!("does not match {}", missed.join(", ")));
+    } else {
+        report.pass(name, "matches every test file in the manifest");
+    }
+}
+
+/// Translates the glob subset used in vitest configs (`**`, `*`, `?`)
+fn glob_to_regex(glob: &str) -> Regex {
+    let mut pattern = String::from("^");
+    let mut rest = glob.trim_start_matches("./");
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**/") {
+            pattern.push_str("(?:.*/)?");
+            rest = after;
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix("**") {
+            pattern.push_str(".*");
+            rest = after;
+            continue;
+        }
+        match c {
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            _ => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    pattern.push('$');
+    Regex::new(&pattern).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::{DryRunFileSystem, RealFileSystem};
+    use crate::manifest::{ManifestFields, TestFramework, TutorialType};
+    use crate::bootstrap::render_package_json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn status(report: &HealthReport, name: &str) -> CheckStatus {
+        report.checks.iter().find(|check| check.name == name).unwrap().status
+    }
+
+    /// A tutorial with every scaffolded file, as `--offline` leaves it
+    fn tutorial() -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("tutorials/my-tutorial");
+        for sub in ["tests", "scripts", "my-tutorial-code"] {
+            fs::create_dir_all(dir.join(sub)).unwrap();
+        }
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        fs::write(dir.join(MANIFEST_FILE), manifest.to_yaml().unwrap()).unwrap();
+        fs::write(dir.join("tests/my-tutorial-e2e.test.ts"), "").unwrap();
+        fs::write(dir.join("package.json"), render_package_json("my-tutorial", TestFramework::Vitest, &Default::default())).unwrap();
+        fs::write(dir.join("vitest.config.ts"), "export default { test: { include: ['tests/**/*.test.ts'] } };\n").unwrap();
+        for file in ["README.md", "justfile", "tsconfig.json", "rust-toolchain.toml", ".gitignore"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        (temp_dir, dir)
+    }
+
+    #[test]
+    fn test_complete_tutorial_only_warns_about_node_modules() {
+        let (_temp, dir) = tutorial();
+        let report = check_tutorial(&RealFileSystem, &dir);
+
+        assert!(!report.has_failures(), "{:?}", report);
+        assert_eq!(report.count(CheckStatus::Warn), 1);
+        assert_eq!(status(&report, "node_modules"), CheckStatus::Warn);
+        assert_eq!(status(&report, "my-tutorial-code/"), CheckStatus::Pass);
+
+        fs::create_dir_all(dir.join("node_modules/vitest")).unwrap();
+        assert_eq!(check_tutorial(&RealFileSystem, &dir).count(CheckStatus::Warn), 0);
+    }
+
+    #[test]
+    fn test_reports_every_problem() {
+        let (_temp, dir) = tutorial();
+        fs::remove_file(dir.join("README.md")).unwrap();
+        fs::remove_file(dir.join(".gitignore")).unwrap();
+        fs::write(dir.join("package.json"), "{\"scripts\": {}}").unwrap();
+        fs::write(dir.join("vitest.config.ts"), "include: ['src/**/*.spec.ts'],\n").unwrap();
+        fs::remove_file(dir.join("tests/my-tutorial-e2e.test.ts")).unwrap();
+
+        let report = check_tutorial(&RealFileSystem, &dir);
+        assert_eq!(status(&report, "README.md"), CheckStatus::Fail);
+        assert_eq!(status(&report, ".gitignore"), CheckStatus::Warn);
+        assert_eq!(status(&report, "package.json scripts"), CheckStatus::Fail);
+        assert_eq!(status(&report, "vitest include"), CheckStatus::Fail);
+        assert_eq!(status(&report, "manifest"), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_rust_test_crate_replaces_npm_project() {
+        let (_temp, dir) = tutorial();
+        let fields = ManifestFields {
+            test_framework: Some("runtime".to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest.to_yaml().unwrap()).unwrap();
+        fs::write(dir.join("tests/my-tutorial-runtime.rs"), "").unwrap();
+        for file in NPM_TEST_FILES {
+            fs::remove_file(dir.join(file)).unwrap();
+        }
+
+        let report = check_tutorial(&RealFileSystem, &dir);
+        assert_eq!(status(&report, "tests/Cargo.toml"), CheckStatus::Fail);
+        assert!(!report.checks.iter().any(|check| check.name == "package.json" || check.name == "node_modules"));
+
+        fs::write(dir.join("tests/Cargo.toml"), "").unwrap();
+        let report = check_tutorial(&RealFileSystem, &dir);
+        assert_eq!(report.count(CheckStatus::Pass), report.checks.len(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_manifest_sees_planned_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("tutorials/my-tutorial");
+        let fs = DryRunFileSystem::new();
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        fs.create_dir_all(&dir.join("my-tutorial-code")).unwrap();
+        fs.write(&dir.join(MANIFEST_FILE), &manifest.to_yaml().unwrap()).unwrap();
+        fs.write(&dir.join("tests/my-tutorial-e2e.test.ts"), "").unwrap();
+
+        let report = check_tutorial(&fs, &dir);
+        assert_eq!(status(&report, "manifest"), CheckStatus::Pass, "{:?}", report);
+    }
+
+    #[test]
+    fn test_glob_to_regex() {
+        let glob = glob_to_regex("tests/**/*.test.ts");
+        assert!(glob.is_match("tests/a.test.ts"));
+        assert!(glob.is_match("tests/nested/a.test.ts"));
+        assert!(!glob.is_match("src/a.test.ts"));
+        assert!(!glob.is_match("tests/a.test.tsx"));
+        assert!(glob_to_regex("./**/*.test.?s").is_match("x/y.test.js"));
+    }
+
+    #[test]
+    fn test_json_report_shape() {
+        let mut report = HealthReport::default();
+        report.pass("a", "ok");
+        report.fail("b", "broken");
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["checks"][1]["status"], "fail");
+        assert!(report.has_failures());
+    }
+}
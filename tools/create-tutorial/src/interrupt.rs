@@ -0,0 +1,20 @@
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Installs a Ctrl-C handler that flags the interruption instead of killing
+/// the process, so the creation pipeline can stop and roll back cleanly.
+/// Child processes still receive the signal and exit on their own.
+pub fn install_handler() -> Result<()> {
+    ctrlc::set_handler(|| INTERRUPTED.store(true, Ordering::SeqCst))?;
+    Ok(())
+}
+
+/// Fails if Ctrl-C was pressed since the handler was installed
+pub fn check() -> Result<()> {
+    if INTERRUPTED.load(Ordering::SeqCst) {
+        anyhow::bail!("Interrupted by Ctrl-C");
+    }
+    Ok(())
+}
@@ -1,5 +1,25 @@
 pub mod bootstrap;
+pub mod catalog;
+pub mod context;
+pub mod doctor;
+pub mod drift;
+pub mod filesystem;
 pub mod git;
+pub mod harness;
+pub mod health;
+pub mod interrupt;
+pub mod manifest;
+pub mod output;
+pub mod pipeline;
+pub mod plan;
+pub mod remove;
+pub mod rename;
+pub mod results;
+pub mod runner;
 pub mod scaffold;
 pub mod templates;
+pub mod title;
+pub mod upgrade;
 pub mod validator;
+pub mod versions;
+pub mod wizard;
@@ -1,59 +1,666 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::builder::PossibleValuesParser;
+use clap::{Args, Parser, Subcommand};
 use colored::*;
-use std::path::PathBuf;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use create_tutorial::{bootstrap, git, scaffold, validator};
+use create_tutorial::context::Context;
+use create_tutorial::filesystem::{DryRunFileSystem, RealFileSystem};
+use create_tutorial::git::{GitOptions, DEFAULT_BRANCH_PREFIX};
+use create_tutorial::harness::{TestOptions, TestReport, DEFAULT_JOBS, DEFAULT_NODE_TIMEOUT};
+use create_tutorial::manifest::{Manifest, ManifestFields, TutorialType, KNOWN_TEST_FRAMEWORKS};
+use create_tutorial::output::OutputFormat;
+use create_tutorial::runner::{RecordingRunner, SystemRunner};
+use create_tutorial::pipeline::CreateOptions;
+use create_tutorial::title::TitleDictionary;
+use create_tutorial::versions::VersionsFile;
+use create_tutorial::wizard::Wizard;
+use create_tutorial::{catalog, doctor, drift, harness, health, interrupt, pipeline, plan, remove, rename, results, scaffold, upgrade, validator};
 
 #[derive(Parser)]
 #[command(name = "create-tutorial")]
 #[command(about = "Create a new Polkadot Cookbook tutorial", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    /// Tutorial slug (e.g., "my-tutorial")
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Tutorial slug (e.g., "my-tutorial"). Omit it to answer the questions interactively.
     #[arg(value_name = "SLUG")]
-    slug: String,
+    slug: Option<String>,
+
+    /// Print the files and commands that would be created/run without touching anything
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Keep partially created files and the new branch if creation fails
+    #[arg(long)]
+    keep_on_failure: bool,
+
+    /// Don't run npm; write package.json with versions pinned from versions.yml
+    #[arg(long)]
+    offline: bool,
+
+    #[command(flatten)]
+    manifest: ManifestArgs,
+
+    #[command(flatten)]
+    git: GitArgs,
+}
+
+/// How the new tutorial is put on a git branch
+#[derive(Args)]
+#[command(next_help_heading = "Git")]
+struct GitArgs {
+    /// Don't create a branch, check the working tree or commit
+    #[arg(long, conflicts_with_all = ["branch_prefix", "require_clean", "check_base", "commit"])]
+    no_git: bool,
+
+    /// Prefix of the tutorial branch name
+    #[arg(long, value_name = "PREFIX", default_value = DEFAULT_BRANCH_PREFIX)]
+    branch_prefix: String,
+
+    /// Refuse to run when the working tree has uncommitted changes
+    #[arg(long)]
+    require_clean: bool,
+
+    /// Refuse to run when the current branch is behind origin/<BRANCH> (fetches it first)
+    #[arg(long, value_name = "BRANCH", num_args = 0..=1, require_equals = true, default_missing_value = "main")]
+    check_base: Option<String>,
+
+    /// Commit the scaffolded files with "feat(tutorial): add <SLUG>"
+    #[arg(long)]
+    commit: bool,
+}
+
+impl GitArgs {
+    fn into_options(self) -> GitOptions {
+        GitOptions {
+            enabled: !self.no_git,
+            branch_prefix: self.branch_prefix,
+            require_clean: self.require_clean,
+            base_branch: self.check_base,
+            commit: self.commit,
+        }
+    }
+}
+
+/// Values written to tutorial.yml; anything left out keeps the scaffold default
+/// (or is asked for in interactive mode)
+#[derive(Args)]
+#[command(next_help_heading = "Manifest")]
+struct ManifestArgs {
+    /// Tutorial title [default: derived from the slug]
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Short description of the tutorial
+    #[arg(long)]
+    description: Option<String>,
+
+    /// Tutorial category [default: polkadot-sdk-cookbook]
+    #[arg(long)]
+    category: Option<String>,
+
+    /// Tutorial type; selects the scaffold template [default: sdk]
+    #[arg(long = "type", visible_alias = "template", value_enum)]
+    tutorial_type: Option<TutorialType>,
+
+    /// The tutorial's tests need a running node (default)
+    #[arg(long, conflicts_with = "no_node")]
+    needs_node: bool,
+
+    /// The tutorial's tests don't need a running node
+    #[arg(long)]
+    no_node: bool,
+
+    /// Relay chain the parachain connects to [default: rococo-local]
+    #[arg(long)]
+    relay_chain: Option<String>,
+
+    /// Parachain id [default: 1000]
+    #[arg(long)]
+    para_id: Option<u32>,
+
+    /// Directory for the tutorial's code [default: <SLUG>-code]
+    #[arg(long)]
+    project_dir: Option<String>,
+
+    /// Test framework [default: vitest]
+    #[arg(long, value_parser = PossibleValuesParser::new(KNOWN_TEST_FRAMEWORKS))]
+    test_framework: Option<String>,
+}
+
+impl ManifestArgs {
+    fn into_fields(self) -> ManifestFields {
+        ManifestFields {
+            title: self.title,
+            description: self.description,
+            category: self.category,
+            tutorial_type: self.tutorial_type,
+            needs_node: match (self.needs_node, self.no_node) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            },
+            relay_chain: self.relay_chain,
+            para_id: self.para_id,
+            project_dir: self.project_dir,
+            test_framework: self.test_framework,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Check an existing tutorial against the cookbook conventions
+    Validate {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Check that a tutorial's setup is complete (files, npm scripts, vitest config, manifest)
+    Check {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Check this machine for the tools tutorials need, at the versions in versions.yml
+    Doctor {
+        /// Use this tutorial's versions.yml overrides
+        #[arg(value_name = "SLUG")]
+        slug: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Build a tutorial, start a node on its runtime, run its tests and stop the node
+    Test {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Seconds to wait for the node's RPC to come up
+        #[arg(long, value_name = "SECS", default_value_t = DEFAULT_NODE_TIMEOUT.as_secs())]
+        node_timeout: u64,
+
+        /// Write <SLUG>.json (summary) and <SLUG>.xml (JUnit) with per-phase results to this directory
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<PathBuf>,
+    },
+    /// Run `test` for every tutorial, several at a time, each node on its own ports
+    TestAll {
+        /// Tutorials to test at once
+        #[arg(long, short, value_name = "N", default_value_t = DEFAULT_JOBS)]
+        jobs: usize,
+
+        /// Seconds to wait for each node's RPC to come up
+        #[arg(long, value_name = "SECS", default_value_t = DEFAULT_NODE_TIMEOUT.as_secs())]
+        node_timeout: u64,
+
+        /// Write <SLUG>.json (summary) and <SLUG>.xml (JUnit) for every tutorial to this directory
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<PathBuf>,
+    },
+    /// List all tutorials in the repository
+    List {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Show the effective tool versions for a tutorial
+    Versions {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Compare against the tutorial's package.json, rust-toolchain.toml and scripts
+        #[arg(long)]
+        check: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Rename a tutorial and every reference to its slug
+    Rename {
+        /// Current tutorial slug
+        #[arg(value_name = "OLD")]
+        old: String,
+
+        /// New tutorial slug
+        #[arg(value_name = "NEW")]
+        new: String,
+
+        /// Prefix of the tutorial branch to rename along with it
+        #[arg(long, value_name = "PREFIX", default_value = DEFAULT_BRANCH_PREFIX)]
+        branch_prefix: String,
+
+        /// Don't rename the git branch
+        #[arg(long)]
+        no_git: bool,
+    },
+    /// Delete a tutorial (or archive it) and its versions.yml overrides
+    Remove {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Move the tutorial to tutorials/_archive/ instead of deleting it
+        #[arg(long)]
+        archive: bool,
+
+        /// Print what would be removed and the remaining references without touching anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Bring a tutorial's generated files up to date with the current scaffold
+    Upgrade {
+        /// Tutorial slug (e.g., "my-tutorial")
+        #[arg(value_name = "SLUG")]
+        slug: String,
+
+        /// Show the diff without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Resolve conflicts by taking the scaffold's version, e.g. for
+        /// tutorials created before .scaffold.json existed
+        #[arg(long)]
+        accept: bool,
+    },
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    match cli.command {
+        Some(Commands::Validate { slug, format }) => validate(&slug, format),
+        Some(Commands::Check { slug, format }) => check(&slug, format),
+        Some(Commands::Doctor { slug, format }) => doctor(slug.as_deref(), format),
+        Some(Commands::Test {
+            slug,
+            node_timeout,
+            report_dir,
+        }) => test(&slug, Duration::from_secs(node_timeout), report_dir.as_deref()),
+        Some(Commands::TestAll {
+            jobs,
+            node_timeout,
+            report_dir,
+        }) => test_all(jobs, Duration::from_secs(node_timeout), report_dir.as_deref()),
+        Some(Commands::List { format }) => list(format),
+        Some(Commands::Versions { slug, check, format }) => versions(&slug, check, format),
+        Some(Commands::Rename {
+            old,
+            new,
+            branch_prefix,
+            no_git,
+        }) => {
+            let git = GitOptions {
+                enabled: !no_git,
+                branch_prefix,
+                ..Default::default()
+            };
+            rename(&old, &new, &git)
+        }
+        Some(Commands::Remove { slug, archive, dry_run }) => remove(&slug, archive, dry_run),
+        Some(Commands::Upgrade { slug, dry_run, accept }) => upgrade(&slug, dry_run, accept),
+        None => {
+            if cli.slug.is_none() && !io::stdin().is_terminal() {
+                eprintln!("{}", "❌ Missing tutorial slug!".red());
+                eprintln!("{}", "ℹ️  Usage: create-tutorial <SLUG>".cyan());
+                std::process::exit(1);
+            }
+            let options = CreateOptions {
+                keep_on_failure: cli.keep_on_failure,
+                offline: cli.offline,
+                git: cli.git.into_options(),
+            };
+            create(cli.slug.as_deref(), &cli.manifest.into_fields(), cli.dry_run, &options)
+        }
+    }
+}
+
+fn create(slug: Option<&str>, fields: &ManifestFields, dry_run: bool, options: &CreateOptions) -> Result<()> {
     println!("\n{}\n", "🚀 Polkadot Cookbook - Tutorial Creator".blue().bold());
 
     // Validate working directory
     validator::validate_working_directory()?;
 
-    // Validate slug format
-    if !validator::is_valid_slug(&cli.slug) {
-        eprintln!("{}", "❌ Invalid tutorial slug format!".red());
-        eprintln!("{}", "ℹ️  Slug must be lowercase, with words separated by dashes.".cyan());
-        eprintln!("{}", "ℹ️  Examples: \"my-tutorial\", \"add-nft-pallet\", \"zero-to-hero\"".cyan());
-        std::process::exit(1);
-    }
+    let manifest = match slug {
+        Some(slug) => {
+            // Validate slug format
+            ensure_valid_slug(slug);
+            let mut fields = fields.clone();
+            if fields.title.is_none() {
+                fields.title = Some(TitleDictionary::load(Path::new(""))?.title(slug));
+            }
+            match Manifest::from_fields(slug, &fields) {
+                Ok(manifest) => manifest,
+                Err(err) => {
+                    eprintln!("{}", format!("❌ {:#}", err).red());
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => {
+            println!("{}\n", "Answer a few questions to set up your tutorial.".cyan());
+            let stdin = io::stdin();
+            Wizard::new(stdin.lock(), io::stdout()).run(Path::new("tutorials"), fields)?
+        }
+    };
+    let slug = manifest.slug.as_str();
 
     // Check if tutorial already exists
-    let tutorial_dir = PathBuf::from("tutorials").join(&cli.slug);
+    let tutorial_dir = PathBuf::from("tutorials").join(slug);
     if tutorial_dir.exists() {
-        eprintln!("{}", format!("❌ Tutorial \"{}\" already exists!", cli.slug).red());
+        eprintln!("{}", format!("❌ Tutorial \"{}\" already exists!", slug).red());
         eprintln!("{}", format!("ℹ️  Directory: {}", tutorial_dir.display()).cyan());
         std::process::exit(1);
     }
 
-    println!("{}\n", format!("Creating tutorial: {}", cli.slug).cyan());
-
-    // Step 1: Create git branch
-    git::create_git_branch(&cli.slug)?;
+    if dry_run {
+        println!("{}\n", format!("Planning tutorial (dry run): {}", slug).cyan());
 
-    // Step 2: Scaffold structure
-    scaffold::scaffold_structure(&cli.slug)?;
+        let fs = DryRunFileSystem::new();
+        let runner = RecordingRunner::new();
+        pipeline::create_tutorial(&Context::new(&fs, &runner).as_dry_run(), &manifest, options)?;
+        plan::print_plan(&fs, &runner);
+        return Ok(());
+    }
 
-    // Step 3: Bootstrap tests
-    bootstrap::bootstrap_tests(&cli.slug)?;
+    println!("{}\n", format!("Creating tutorial: {}", slug).cyan());
 
-    // Step 4: Verify setup
-    scaffold::verify_setup(&cli.slug)?;
+    interrupt::install_handler()?;
+    let report = pipeline::create_tutorial(&Context::new(&RealFileSystem, &SystemRunner), &manifest, options)?;
+    if report.has_failures() {
+        eprintln!("{}", format!("❌ Tutorial {} was created, but its setup is incomplete.", slug).red());
+        eprintln!("{}", format!("ℹ️  Fix the failed checks, then run: create-tutorial check {}", slug).cyan());
+        std::process::exit(1);
+    }
 
     // Success message
-    scaffold::print_success_message(&cli.slug);
+    scaffold::print_success_message(&manifest, &options.git);
+
+    Ok(())
+}
+
+fn validate(slug: &str, format: OutputFormat) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let tutorial_dir = PathBuf::from("tutorials").join(slug);
+    let violations = validator::validate_tutorial(&tutorial_dir);
+    validator::print_violations(&tutorial_dir, &violations, format)?;
+
+    if !violations.is_empty() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn check(slug: &str, format: OutputFormat) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let tutorial_dir = PathBuf::from("tutorials").join(slug);
+    if !tutorial_dir.is_dir() {
+        anyhow::bail!("Tutorial directory {} does not exist", tutorial_dir.display());
+    }
+    let report = health::check_tutorial(&RealFileSystem, &tutorial_dir);
+    report.print(&tutorial_dir.display().to_string(), format)?;
+
+    if report.has_failures() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn doctor(slug: Option<&str>, format: OutputFormat) -> Result<()> {
+    validator::validate_working_directory()?;
+    if let Some(slug) = slug {
+        ensure_valid_slug(slug);
+    }
 
+    let versions = VersionsFile::load(Path::new(""))?.resolve(slug.unwrap_or_default());
+    let report = doctor::diagnose(&Context::new(&RealFileSystem, &SystemRunner), &versions);
+    let subject = match slug {
+        Some(slug) => format!("tutorial {}", slug),
+        None => "this machine".to_string(),
+    };
+    report.print(&subject, format)?;
+
+    if report.has_failures() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn test(slug: &str, node_timeout: Duration, report_dir: Option<&Path>) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let tutorial_dir = PathBuf::from("tutorials").join(slug);
+    if !tutorial_dir.is_dir() {
+        anyhow::bail!("Tutorial directory {} does not exist", tutorial_dir.display());
+    }
+
+    println!("{}\n", format!("Testing tutorial: {}", slug).cyan());
+    interrupt::install_handler()?;
+    let options = TestOptions {
+        node_timeout,
+        ..Default::default()
+    };
+    let report = harness::test_tutorial(&Context::new(&RealFileSystem, &SystemRunner), slug, &options)?;
+    report.print();
+    if let Some(report_dir) = report_dir {
+        write_reports(report_dir, &report)?;
+    }
+
+    if report.has_failures() {
+        eprintln!("\n{}", format!("❌ Testing {} failed", slug).red());
+        std::process::exit(1);
+    }
+
+    println!("\n{}", format!("✅ All phases passed for {}", slug).green());
+    Ok(())
+}
+
+fn test_all(jobs: usize, node_timeout: Duration, report_dir: Option<&Path>) -> Result<()> {
+    validator::validate_working_directory()?;
+
+    let slugs: Vec<String> = catalog::discover_tutorials(Path::new("tutorials"))?
+        .iter()
+        .filter_map(|entry| entry.dir.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .collect();
+    if slugs.is_empty() {
+        println!("{}", "No tutorials found.".yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}\n",
+        format!("Testing {} tutorials, {} at a time", slugs.len(), jobs.max(1)).cyan()
+    );
+    interrupt::install_handler()?;
+    let options = TestOptions {
+        node_timeout,
+        ..Default::default()
+    };
+    let results = harness::test_all(&slugs, jobs, &options, |slug, options| {
+        harness::test_tutorial(&Context::new(&RealFileSystem, &SystemRunner), slug, options)
+    });
+
+    let mut failed = Vec::new();
+    for (slug, result) in slugs.iter().zip(&results) {
+        match result {
+            Ok(report) => {
+                report.print();
+                if let Some(report_dir) = report_dir {
+                    write_reports(report_dir, report)?;
+                }
+                if let Some(phase) = report.failed_phase() {
+                    failed.push(format!("{} ({})", slug, phase.phase.as_str()));
+                }
+            }
+            Err(err) => {
+                eprintln!("\n{}", format!("❌ {}: {:#}", slug, err).red());
+                failed.push(slug.clone());
+            }
+        }
+    }
+
+    println!();
+    if !failed.is_empty() {
+        eprintln!(
+            "{}",
+            format!("❌ {} of {} tutorials failed: {}", failed.len(), slugs.len(), failed.join(", ")).red()
+        );
+        std::process::exit(1);
+    }
+
+    println!("{}", format!("✅ All {} tutorials passed", slugs.len()).green());
+    Ok(())
+}
+
+fn write_reports(report_dir: &Path, report: &TestReport) -> Result<()> {
+    let paths = results::write_reports(&RealFileSystem, report_dir, report)?;
+    let paths: Vec<_> = paths.iter().map(|path| path.display().to_string()).collect();
+    println!("{}", format!("ℹ️  Wrote {}", paths.join(", ")).cyan());
+    Ok(())
+}
+
+fn list(format: OutputFormat) -> Result<()> {
+    validator::validate_working_directory()?;
+
+    let entries = catalog::discover_tutorials(Path::new("tutorials"))?;
+    catalog::print_catalog(&entries, format)
+}
+
+fn versions(slug: &str, check: bool, format: OutputFormat) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let resolved = VersionsFile::load(Path::new(""))?.resolve(slug);
+    let drift = if check {
+        let tutorial_dir = PathBuf::from("tutorials").join(slug);
+        if !tutorial_dir.is_dir() {
+            anyhow::bail!("Tutorial directory {} does not exist", tutorial_dir.display());
+        }
+        Some(drift::check_drift(&tutorial_dir, &resolved)?)
+    } else {
+        None
+    };
+
+    drift::print_versions(slug, &resolved, drift.as_deref(), format)?;
+
+    if drift.is_some_and(|drift| !drift.is_empty()) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn rename(old: &str, new: &str, git: &GitOptions) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(old);
+    ensure_valid_slug(new);
+
+    let ctx = Context::new(&RealFileSystem, &SystemRunner);
+    let plan = rename::plan_rename(&ctx, old, new, git)?;
+
+    println!("{}", format!("Renaming tutorial {} -> {}:", old, new).cyan());
+    rename::print_plan(&plan);
+    rename::apply_rename(&ctx, &plan)?;
+
+    println!("{}", format!("✅ Renamed tutorial to {}", new).green());
+    Ok(())
+}
+
+fn remove(slug: &str, archive: bool, dry_run: bool) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let ctx = Context::new(&RealFileSystem, &SystemRunner);
+    let plan = remove::plan_remove(&ctx, slug, archive)?;
+
+    let action = if archive { "Archiving" } else { "Removing" };
+    let suffix = if dry_run { " (dry run)" } else { "" };
+    println!("{}", format!("{} tutorial{}: {}", action, suffix, slug).cyan());
+    remove::print_plan(&plan);
+    if !dry_run {
+        remove::apply_remove(&ctx, &plan)?;
+    }
+    remove::print_references(&plan);
+
+    if !dry_run {
+        let done = if archive { "Archived" } else { "Removed" };
+        println!("\n{}", format!("✅ {} tutorial {}", done, slug).green());
+    }
+    Ok(())
+}
+
+fn upgrade(slug: &str, dry_run: bool, accept: bool) -> Result<()> {
+    validator::validate_working_directory()?;
+    ensure_valid_slug(slug);
+
+    let ctx = Context::new(&RealFileSystem, &SystemRunner);
+    let mut plan = upgrade::plan_upgrade(&ctx, slug)?;
+    if accept {
+        plan.accept_conflicts();
+    }
+
+    let suffix = if dry_run { " (dry run)" } else { "" };
+    println!("{}\n", format!("Upgrading tutorial{}: {}", suffix, slug).cyan());
+    upgrade::print_plan(&plan);
+    if !dry_run {
+        upgrade::apply_upgrade(&ctx, &plan)?;
+    }
+
+    let conflicts: Vec<_> = plan.conflicts().map(|file| file.path.as_str()).collect();
+    if !conflicts.is_empty() {
+        eprintln!(
+            "\n{}",
+            format!("❌ Could not merge {}; left unchanged", conflicts.join(", ")).red()
+        );
+        eprintln!(
+            "{}",
+            "ℹ️  Apply the diff above by hand and run upgrade again, or rerun with --accept to take the scaffold's version."
+                .cyan()
+        );
+        std::process::exit(1);
+    }
+
+    if !dry_run {
+        println!("\n{}", format!("✅ Tutorial {} is up to date with the scaffold", slug).green());
+    }
     Ok(())
 }
+
+fn ensure_valid_slug(slug: &str) {
+    if validator::is_reserved_slug(slug) {
+        eprintln!("{}", format!("❌ \"{}\" is reserved by versions.yml!", slug).red());
+        eprintln!("{}", "ℹ️  Pick another slug; \"npm\" and \"versions\" are top-level keys there.".cyan());
+        std::process::exit(1);
+    }
+    if !validator::is_valid_slug(slug) {
+        eprintln!("{}", "❌ Invalid tutorial slug format!".red());
+        eprintln!("{}", "ℹ️  Slug must be lowercase, with words separated by dashes.".cyan());
+        eprintln!("{}", "ℹ️  Examples: \"my-tutorial\", \"add-nft-pallet\", \"zero-to-hero\"".cyan());
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,702 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::filesystem::FileSystem;
+use crate::validator::{is_valid_slug, slug_to_title, Violation};
+
+/// File name of the tutorial manifest inside `tutorials/<slug>/`
+pub const MANIFEST_FILE: &str = "tutorial.yml";
+
+/// Default category for cookbook tutorials
+pub const DEFAULT_CATEGORY: &str = "polkadot-sdk-cookbook";
+
+/// Placeholder description written into freshly scaffolded manifests
+pub const PLACEHOLDER_DESCRIPTION: &str = "Replace with a short description.";
+
+/// Test frameworks the cookbook knows how to run
+pub const KNOWN_TEST_FRAMEWORKS: &[&str] = &["vitest", "papi", "subxt", "runtime"];
+
+/// Runtime built by tutorials that reuse the kitchensink parachain
+pub const KITCHENSINK_WASM_PATH: &str =
+    "./target/release/wbuild/parachain-template-runtime/parachain_template_runtime.compact.compressed.wasm";
+
+/// Typed model of a tutorial's `tutorial.yml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub needs_node: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub tutorial_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<ManifestSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scripts_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zombienet_dir: Option<String>,
+}
+
+/// The `manifest:` section describing how to build and test a tutorial
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManifestSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<BuildSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<RuntimeSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<NetworkSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tests: Option<TestsSpec>,
+}
+
+/// `manifest.build`: where the code lives and how to build it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BuildSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<String>,
+}
+
+/// `manifest.runtime`: location of the built runtime
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_path: Option<String>,
+}
+
+/// `manifest.network`: relay chain and parachain id used by the tutorial
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay_chain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub para_id: Option<u32>,
+}
+
+/// `manifest.tests`: test framework and test files
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestsSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+}
+
+/// Manifest values chosen explicitly (command-line flags or wizard answers).
+/// `None` keeps the scaffold default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ManifestFields {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub tutorial_type: Option<TutorialType>,
+    pub needs_node: Option<bool>,
+    pub relay_chain: Option<String>,
+    pub para_id: Option<u32>,
+    pub project_dir: Option<String>,
+    pub test_framework: Option<String>,
+}
+
+/// Kind of tutorial, stored in the manifest's `type` field. Also selects the
+/// scaffold template (`--template`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum TutorialType {
+    /// Pallet/parachain tutorial built on the kitchensink parachain
+    #[default]
+    Sdk,
+    /// Smart contract tutorial
+    Contracts,
+    /// Cross-chain messaging tutorial
+    Xcm,
+    /// Runtime upgrade tutorial
+    RuntimeUpgrade,
+}
+
+impl TutorialType {
+    pub const ALL: &'static [TutorialType] = &[
+        TutorialType::Sdk,
+        TutorialType::Contracts,
+        TutorialType::Xcm,
+        TutorialType::RuntimeUpgrade,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TutorialType::Sdk => "sdk",
+            TutorialType::Contracts => "contracts",
+            TutorialType::Xcm => "xcm",
+            TutorialType::RuntimeUpgrade => "runtime-upgrade",
+        }
+    }
+
+    /// Whether the tutorial builds and runs the kitchensink parachain
+    pub fn uses_kitchensink(&self) -> bool {
+        !matches!(self, TutorialType::Contracts)
+    }
+}
+
+impl fmt::Display for TutorialType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TutorialType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        TutorialType::ALL
+            .iter()
+            .copied()
+            .find(|t| t.as_str() == s)
+            .ok_or_else(|| anyhow::anyhow!("unknown tutorial type \"{}\"", s))
+    }
+}
+
+/// Test scaffold a tutorial uses, stored in the manifest's
+/// `tests.framework` field
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TestFramework {
+    /// TypeScript tests run by vitest against the node with `@polkadot/api`
+    #[default]
+    Vitest,
+    /// TypeScript tests run by vitest against the node with `polkadot-api`
+    Papi,
+    /// Rust integration-test crate talking to the node through `subxt`
+    Subxt,
+    /// Rust tests driving the kitchensink runtime's `Executive` in
+    /// `TestExternalities`, without a node
+    Runtime,
+}
+
+impl TestFramework {
+    pub const ALL: &'static [TestFramework] = &[
+        TestFramework::Vitest,
+        TestFramework::Papi,
+        TestFramework::Subxt,
+        TestFramework::Runtime,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TestFramework::Vitest => "vitest",
+            TestFramework::Papi => "papi",
+            TestFramework::Subxt => "subxt",
+            TestFramework::Runtime => "runtime",
+        }
+    }
+
+    /// Whether the tests are a Rust crate under `tests/` rather than an npm
+    /// project
+    pub fn is_rust(&self) -> bool {
+        matches!(self, TestFramework::Subxt | TestFramework::Runtime)
+    }
+
+    /// Whether the tests talk to a running node
+    pub fn needs_node(&self) -> bool {
+        !matches!(self, TestFramework::Runtime)
+    }
+
+    /// The scaffolded test file, relative to the tutorial
+    pub fn test_file(&self, slug: &str) -> String {
+        match self {
+            TestFramework::Vitest | TestFramework::Papi => format!("tests/{}-e2e.test.ts", slug),
+            TestFramework::Subxt => format!("tests/{}-e2e.rs", slug),
+            TestFramework::Runtime => format!("tests/{}-runtime.rs", slug),
+        }
+    }
+}
+
+impl fmt::Display for TestFramework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TestFramework {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        TestFramework::ALL
+            .iter()
+            .copied()
+            .find(|f| f.as_str() == s)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "unknown test framework \"{}\" (expected one of: {})",
+                    s,
+                    KNOWN_TEST_FRAMEWORKS.join(", ")
+                )
+            })
+    }
+}
+
+impl Manifest {
+    /// Builds the manifest written for a freshly scaffolded tutorial
+    pub fn new(slug: &str, title: &str, tutorial_type: TutorialType) -> Self {
+        let build_command = match tutorial_type {
+            TutorialType::Contracts => "cargo contract build --release",
+            _ => "cargo build --release",
+        };
+        let (runtime, network) = if tutorial_type.uses_kitchensink() {
+            (
+                Some(RuntimeSpec {
+                    wasm_path: Some(KITCHENSINK_WASM_PATH.to_string()),
+                }),
+                Some(NetworkSpec {
+                    relay_chain: Some("rococo-local".to_string()),
+                    para_id: Some(1000),
+                }),
+            )
+        } else {
+            (None, None)
+        };
+
+        Manifest {
+            name: title.to_string(),
+            slug: slug.to_string(),
+            category: DEFAULT_CATEGORY.to_string(),
+            needs_node: true,
+            description: Some(PLACEHOLDER_DESCRIPTION.to_string()),
+            tutorial_type: Some(tutorial_type.to_string()),
+            manifest: Some(ManifestSpec {
+                build: Some(BuildSpec {
+                    project_dir: Some(format!("{}-code", slug)),
+                    commands: vec![build_command.to_string()],
+                }),
+                runtime,
+                network,
+                tests: Some(TestsSpec {
+                    framework: Some(TestFramework::Vitest.to_string()),
+                    files: vec![TestFramework::Vitest.test_file(slug)],
+                }),
+            }),
+            scripts_dir: None,
+            zombienet_dir: None,
+        }
+    }
+
+    /// Builds the manifest for a new tutorial, applying `fields` over the
+    /// defaults. The title falls back to one derived from the slug.
+    pub fn from_fields(slug: &str, fields: &ManifestFields) -> Result<Self> {
+        let title = fields.title.clone().unwrap_or_else(|| slug_to_title(slug));
+        let mut manifest = Manifest::new(slug, &title, fields.tutorial_type.unwrap_or_default());
+        manifest.apply(fields)?;
+        Ok(manifest)
+    }
+
+    /// Overwrites the fields set in `fields`, rejecting values the
+    /// cookbook could not scaffold (empty text, unknown test framework,
+    /// project_dir outside the tutorial directory, runtime tests without
+    /// the kitchensink runtime). Choosing a test framework also points
+    /// `tests.files` at its scaffold and, unless `needs_node` is set too,
+    /// whether a node is needed.
+    pub fn apply(&mut self, fields: &ManifestFields) -> Result<()> {
+        fn non_empty(field: &str, value: &str) -> Result<String> {
+            if value.trim().is_empty() {
+                anyhow::bail!("{} must not be empty", field);
+            }
+            Ok(value.trim().to_string())
+        }
+
+        if let Some(title) = &fields.title {
+            self.name = non_empty("title", title)?;
+        }
+        if let Some(description) = &fields.description {
+            self.description = Some(non_empty("description", description)?);
+        }
+        if let Some(category) = &fields.category {
+            self.category = non_empty("category", category)?;
+        }
+        if let Some(tutorial_type) = fields.tutorial_type {
+            self.tutorial_type = Some(tutorial_type.to_string());
+        }
+        if let Some(needs_node) = fields.needs_node {
+            self.needs_node = needs_node;
+        }
+
+        let spec = self.manifest.get_or_insert_with(ManifestSpec::default);
+        if fields.relay_chain.is_some() || fields.para_id.is_some() {
+            let network = spec.network.get_or_insert_with(NetworkSpec::default);
+            if let Some(relay_chain) = &fields.relay_chain {
+                network.relay_chain = Some(non_empty("relay chain", relay_chain)?);
+            }
+            if let Some(para_id) = fields.para_id {
+                network.para_id = Some(para_id);
+            }
+        }
+        if let Some(project_dir) = &fields.project_dir {
+            let project_dir = non_empty("project dir", project_dir)?;
+            let path = Path::new(&project_dir);
+            if path.is_absolute() || path.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+                anyhow::bail!("project dir \"{}\" must be a path inside the tutorial directory", project_dir);
+            }
+            spec.build.get_or_insert_with(BuildSpec::default).project_dir = Some(project_dir);
+        }
+        if let Some(framework) = &fields.test_framework {
+            let framework: TestFramework = framework.parse()?;
+            if framework == TestFramework::Runtime && !self.template().uses_kitchensink() {
+                anyhow::bail!(
+                    "runtime tests need the kitchensink runtime, which {} tutorials don't use",
+                    self.template()
+                );
+            }
+            let spec = self.manifest.get_or_insert_with(ManifestSpec::default);
+            let tests = spec.tests.get_or_insert_with(TestsSpec::default);
+            tests.framework = Some(framework.to_string());
+            tests.files = vec![framework.test_file(&self.slug)];
+            if fields.needs_node.is_none() {
+                self.needs_node = framework.needs_node();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The tutorial type, defaulting to `sdk` when missing or unknown
+    pub fn template(&self) -> TutorialType {
+        self.tutorial_type
+            .as_deref()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// The test framework, defaulting to `vitest` when missing or unknown
+    pub fn test_framework(&self) -> TestFramework {
+        self.manifest
+            .as_ref()
+            .and_then(|spec| spec.tests.as_ref())
+            .and_then(|tests| tests.framework.as_deref())
+            .and_then(|f| f.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Directory holding the tutorial's code, relative to the tutorial
+    pub fn project_dir(&self) -> String {
+        self.manifest
+            .as_ref()
+            .and_then(|spec| spec.build.as_ref())
+            .and_then(|build| build.project_dir.clone())
+            .unwrap_or_else(|| format!("{}-code", self.slug))
+    }
+
+    /// Parses a manifest from YAML text
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str(content).context("Failed to parse tutorial manifest")
+    }
+
+    /// Reads and parses a manifest file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Serializes the manifest back to YAML
+    pub fn to_yaml(&self) -> Result<String> {
+        serde_yaml::to_string(self).context("Failed to serialize tutorial manifest")
+    }
+
+    /// Checks the manifest against the tutorial directory it lives in,
+    /// returning every problem found rather than stopping at the first one
+    pub fn validate(&self, fs: &dyn FileSystem, tutorial_dir: &Path) -> Vec<Violation> {
+        let file = tutorial_dir.join(MANIFEST_FILE);
+        let mut violations = Vec::new();
+        let mut report = |field: &str, message: String| {
+            violations.push(Violation::new(&file, Some(field), message));
+        };
+
+        if self.name.trim().is_empty() {
+            report("name", "missing tutorial name".to_string());
+        }
+
+        if self.slug.is_empty() {
+            report("slug", "missing tutorial slug".to_string());
+        } else if !is_valid_slug(&self.slug) {
+            report("slug", format!("\"{}\" is not a valid slug", self.slug));
+        } else if let Some(dir_name) = tutorial_dir.file_name().and_then(|n| n.to_str()) {
+            if dir_name != self.slug {
+                report(
+                    "slug",
+                    format!("\"{}\" does not match directory name \"{}\"", self.slug, dir_name),
+                );
+            }
+        }
+
+        if self.category.trim().is_empty() {
+            report("category", "missing category".to_string());
+        }
+
+        if let Some(tutorial_type) = &self.tutorial_type {
+            if tutorial_type.parse::<TutorialType>().is_err() {
+                report(
+                    "type",
+                    format!(
+                        "unknown type \"{}\" (expected one of: {})",
+                        tutorial_type,
+                        TutorialType::ALL.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")
+                    ),
+                );
+            }
+        }
+
+        let Some(spec) = &self.manifest else {
+            return violations;
+        };
+
+        if let Some(build) = &spec.build {
+            match &build.project_dir {
+                None => report("manifest.build.project_dir", "missing project_dir".to_string()),
+                Some(dir) if !fs.is_dir(&tutorial_dir.join(dir)) => report(
+                    "manifest.build.project_dir",
+                    format!("directory \"{}\" does not exist", dir),
+                ),
+                Some(_) => {}
+            }
+        }
+
+        if let Some(network) = &spec.network {
+            if network.relay_chain.as_deref().is_some_and(|r| r.trim().is_empty()) {
+                report("manifest.network.relay_chain", "relay_chain is empty".to_string());
+            }
+        }
+
+        if let Some(tests) = &spec.tests {
+            if let Some(framework) = &tests.framework {
+                if !KNOWN_TEST_FRAMEWORKS.contains(&framework.as_str()) {
+                    report(
+                        "manifest.tests.framework",
+                        format!(
+                            "unknown framework \"{}\" (expected one of: {})",
+                            framework,
+                            KNOWN_TEST_FRAMEWORKS.join(", ")
+                        ),
+                    );
+                }
+            }
+            for (i, test_file) in tests.files.iter().enumerate() {
+                let path = tutorial_dir.join(test_file);
+                if !fs.exists(&path) || fs.is_dir(&path) {
+                    report(
+                        &format!("manifest.tests.files[{}]", i),
+                        format!("test file \"{}\" does not exist", test_file),
+                    );
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::RealFileSystem;
+    use tempfile::TempDir;
+
+    const ZERO_TO_HERO: &str = r#"name: Zero to Hero
+slug: zero-to-hero
+category: polkadot-sdk-cookbook
+needs_node: true
+
+manifest:
+  build:
+    project_dir: zero-to-hero-code
+    commands:
+      - cargo build --release
+  runtime:
+    wasm_path: ./target/release/wbuild/parachain-template-runtime/parachain_template_runtime.compact.compressed.wasm
+  network:
+    relay_chain: rococo-local
+    para_id: 1000
+  tests:
+    framework: vitest
+    files:
+      - tests/zero-to-hero-e2e.test.ts
+
+scripts_dir: scripts
+zombienet_dir: zombienet
+"#;
+
+    fn tutorial_dir(slug: &str) -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join(slug);
+        fs::create_dir_all(&dir).unwrap();
+        (temp_dir, dir)
+    }
+
+    #[test]
+    fn test_parses_full_manifest() {
+        let manifest = Manifest::from_yaml_str(ZERO_TO_HERO).unwrap();
+        assert_eq!(manifest.name, "Zero to Hero");
+        assert!(manifest.needs_node);
+        let spec = manifest.manifest.unwrap();
+        assert_eq!(spec.build.unwrap().commands, vec!["cargo build --release"]);
+        assert_eq!(spec.network.unwrap().para_id, Some(1000));
+        assert_eq!(spec.tests.unwrap().files, vec!["tests/zero-to-hero-e2e.test.ts"]);
+    }
+
+    #[test]
+    fn test_round_trips_through_yaml() {
+        let manifest = Manifest::from_yaml_str(ZERO_TO_HERO).unwrap();
+        let reparsed = Manifest::from_yaml_str(&manifest.to_yaml().unwrap()).unwrap();
+        assert_eq!(manifest, reparsed);
+    }
+
+    #[test]
+    fn test_new_manifest_is_valid_once_scaffolded() {
+        let (_temp, dir) = tutorial_dir("my-tutorial");
+        fs::create_dir_all(dir.join("my-tutorial-code")).unwrap();
+        fs::create_dir_all(dir.join("tests")).unwrap();
+        fs::write(dir.join("tests/my-tutorial-e2e.test.ts"), "").unwrap();
+
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        assert!(manifest.validate(&RealFileSystem, &dir).is_empty());
+    }
+
+    #[test]
+    fn test_contracts_manifest_has_no_parachain_sections() {
+        let manifest = Manifest::new("my-contract", "My Contract", TutorialType::Contracts);
+        let spec = manifest.manifest.unwrap();
+        assert_eq!(spec.build.unwrap().commands, vec!["cargo contract build --release"]);
+        assert!(spec.runtime.is_none());
+        assert!(spec.network.is_none());
+
+        let xcm = Manifest::new("my-xcm", "My XCM", TutorialType::Xcm).manifest.unwrap();
+        assert_eq!(xcm.network.unwrap().para_id, Some(1000));
+    }
+
+    #[test]
+    fn test_from_fields_applies_explicit_values() {
+        let fields = ManifestFields {
+            title: Some("Add NFT Pallet".to_string()),
+            description: Some("Mint NFTs.".to_string()),
+            category: Some("pallets".to_string()),
+            tutorial_type: Some(TutorialType::Contracts),
+            needs_node: Some(false),
+            relay_chain: Some("paseo-local".to_string()),
+            para_id: Some(2000),
+            project_dir: Some("code".to_string()),
+            test_framework: Some("vitest".to_string()),
+        };
+        let manifest = Manifest::from_fields("add-nft-pallet", &fields).unwrap();
+
+        assert_eq!(manifest.name, "Add NFT Pallet");
+        assert_eq!(manifest.description.as_deref(), Some("Mint NFTs."));
+        assert_eq!(manifest.category, "pallets");
+        assert_eq!(manifest.template(), TutorialType::Contracts);
+        assert!(!manifest.needs_node);
+        assert_eq!(manifest.project_dir(), "code");
+        let network = manifest.manifest.unwrap().network.unwrap();
+        assert_eq!(network.relay_chain.as_deref(), Some("paseo-local"));
+        assert_eq!(network.para_id, Some(2000));
+
+        let defaults = Manifest::from_fields("add-nft-pallet", &ManifestFields::default()).unwrap();
+        assert_eq!(defaults, Manifest::new("add-nft-pallet", &slug_to_title("add-nft-pallet"), TutorialType::Sdk));
+    }
+
+    #[test]
+    fn test_from_fields_rejects_unscaffoldable_values() {
+        let reject = |fields: ManifestFields| Manifest::from_fields("my-tutorial", &fields).unwrap_err().to_string();
+
+        assert_eq!(
+            reject(ManifestFields { title: Some(" ".to_string()), ..Default::default() }),
+            "title must not be empty"
+        );
+        assert_eq!(
+            reject(ManifestFields { project_dir: Some("../elsewhere".to_string()), ..Default::default() }),
+            "project dir \"../elsewhere\" must be a path inside the tutorial directory"
+        );
+        assert!(reject(ManifestFields { test_framework: Some("jest".to_string()), ..Default::default() })
+            .starts_with("unknown test framework \"jest\""));
+    }
+
+    #[test]
+    fn test_reports_every_problem_with_field_paths() {
+        let (_temp, dir) = tutorial_dir("zero-to-hero");
+        let mut manifest = Manifest::from_yaml_str(ZERO_TO_HERO).unwrap();
+        manifest.tutorial_type = Some("parachain".to_string());
+        manifest.manifest.as_mut().unwrap().build.as_mut().unwrap().project_dir = None;
+
+        let fields: Vec<_> = manifest
+            .validate(&RealFileSystem, &dir)
+            .into_iter()
+            .map(|v| v.field.unwrap())
+            .collect();
+        assert_eq!(
+            fields,
+            vec!["type", "manifest.build.project_dir", "manifest.tests.files[0]"]
+        );
+    }
+
+    #[test]
+    fn test_reports_slug_mismatch() {
+        let (_temp, dir) = tutorial_dir("other-name");
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        let violations = manifest.validate(&RealFileSystem, &dir);
+        assert!(violations.iter().any(|v| v.field.as_deref() == Some("slug")));
+        assert!(violations[0].file.ends_with("other-name/tutorial.yml"));
+    }
+
+    #[test]
+    fn test_tutorial_type_from_str() {
+        assert_eq!("sdk".parse::<TutorialType>().unwrap(), TutorialType::Sdk);
+        assert_eq!("contracts".parse::<TutorialType>().unwrap(), TutorialType::Contracts);
+        assert_eq!("runtime-upgrade".parse::<TutorialType>().unwrap(), TutorialType::RuntimeUpgrade);
+        assert!("evm".parse::<TutorialType>().is_err());
+    }
+
+    #[test]
+    fn test_known_test_frameworks_match_enum() {
+        let names: Vec<_> = TestFramework::ALL.iter().map(|f| f.as_str()).collect();
+        assert_eq!(names, KNOWN_TEST_FRAMEWORKS);
+    }
+
+    #[test]
+    fn test_test_framework_sets_files_and_node() {
+        let framework = |name: &str, needs_node: Option<bool>| {
+            let fields = ManifestFields {
+                test_framework: Some(name.to_string()),
+                needs_node,
+                ..Default::default()
+            };
+            Manifest::from_fields("my-tutorial", &fields).unwrap()
+        };
+
+        let subxt = framework("subxt", None);
+        assert_eq!(subxt.test_framework(), TestFramework::Subxt);
+        assert!(subxt.needs_node);
+        assert_eq!(subxt.manifest.unwrap().tests.unwrap().files, vec!["tests/my-tutorial-e2e.rs"]);
+
+        let runtime = framework("runtime", None);
+        assert!(!runtime.needs_node);
+        assert_eq!(runtime.manifest.unwrap().tests.unwrap().files, vec!["tests/my-tutorial-runtime.rs"]);
+        assert!(framework("runtime", Some(true)).needs_node);
+        assert_eq!(framework("papi", None).test_framework(), TestFramework::Papi);
+
+        let contracts = ManifestFields {
+            tutorial_type: Some(TutorialType::Contracts),
+            test_framework: Some("runtime".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            Manifest::from_fields("my-contract", &contracts).unwrap_err().to_string(),
+            "runtime tests need the kitchensink runtime, which contracts tutorials don't use"
+        );
+        assert_eq!(Manifest::from_yaml_str("name: x\n").unwrap().test_framework(), TestFramework::Vitest);
+    }
+}
@@ -0,0 +1,11 @@
+use clap::ValueEnum;
+
+/// Output format for commands that report structured results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable, coloured output
+    #[default]
+    Text,
+    /// Machine-readable JSON on stdout
+    Json,
+}
@@ -0,0 +1,343 @@
+use anyhow::Result;
+use colored::*;
+
+use crate::context::Context;
+use crate::filesystem::TrackingFileSystem;
+use crate::git::{self, BranchSwitch, GitOptions};
+use crate::health::HealthReport;
+use crate::manifest::Manifest;
+use crate::versions::{ResolvedVersions, VersionsFile};
+use crate::{bootstrap, interrupt, scaffold};
+
+/// Options controlling how a tutorial is created
+#[derive(Debug, Clone, Default)]
+pub struct CreateOptions {
+    /// Leave created files and the new branch in place when a step fails
+    pub keep_on_failure: bool,
+    /// Render package.json directly instead of running npm
+    pub offline: bool,
+    /// Branch naming, pre-flight checks and auto-commit
+    pub git: GitOptions,
+}
+
+/// Runs every tutorial creation step against the given context. `manifest`
+/// becomes the tutorial's `tutorial.yml`; its slug and type pick the
+/// directory and scaffold template.
+///
+/// Creation is transactional: if a step fails (or Ctrl-C is pressed), every
+/// file and directory created so far is removed and the previously checked
+/// out branch is restored, unless `keep_on_failure` is set. Returns the
+/// health report of the final verification step.
+pub fn create_tutorial(ctx: &Context, manifest: &Manifest, options: &CreateOptions) -> Result<HealthReport> {
+    let slug = manifest.slug.as_str();
+    let versions = VersionsFile::load(&ctx.root)?.resolve(slug);
+    git::preflight(ctx, &options.git)?;
+
+    let tracking = TrackingFileSystem::new(ctx.fs);
+    let tracked = Context {
+        root: ctx.root.clone(),
+        fs: &tracking,
+        runner: ctx.runner,
+        dry_run: ctx.dry_run,
+    };
+    let mut branch_switch = None;
+
+    let result = run_steps(&tracked, manifest, options, &versions, &mut branch_switch);
+
+    if result.is_err() {
+        if options.keep_on_failure {
+            eprintln!("\n{}", "⚠️  Keeping partially created files (--keep-on-failure)".yellow());
+        } else {
+            rollback(ctx, &tracking, branch_switch.as_ref());
+        }
+    }
+
+    result
+}
+
+fn run_steps(
+    ctx: &Context,
+    manifest: &Manifest,
+    options: &CreateOptions,
+    versions: &ResolvedVersions,
+    branch_switch: &mut Option<BranchSwitch>,
+) -> Result<HealthReport> {
+    let slug = manifest.slug.as_str();
+
+    // Step 1: Create git branch
+    *branch_switch = git::create_git_branch(ctx, slug, &options.git)?;
+    interrupt::check()?;
+
+    // Step 2: Scaffold structure
+    scaffold::scaffold_structure(ctx, manifest, versions)?;
+    interrupt::check()?;
+
+    // Step 3: Bootstrap tests
+    bootstrap::bootstrap_tests(ctx, manifest, options, versions)?;
+    interrupt::check()?;
+
+    // Step 4: Verify setup
+    let report = scaffold::verify_setup(ctx, slug)?;
+
+    if options.git.enabled && options.git.commit {
+        if report.has_failures() {
+            eprintln!("{}", "⚠️  Not committing: the setup checks failed".yellow());
+        } else {
+            git::commit_tutorial(ctx, slug)?;
+        }
+    }
+
+    Ok(report)
+}
+
+fn rollback(ctx: &Context, tracking: &TrackingFileSystem, branch_switch: Option<&BranchSwitch>) {
+    eprintln!("\n{}", "↩️  Rolling back tutorial creation...".yellow());
+
+    match tracking.rollback() {
+        Ok(removed) => {
+            for path in removed {
+                eprintln!("{}", format!("  - removed {}", path.display()).yellow());
+            }
+        }
+        Err(err) => eprintln!("{}", format!("❌ Failed to remove created files: {:#}", err).red()),
+    }
+
+    if let Some(switch) = branch_switch {
+        match git::undo_branch_switch(ctx, switch) {
+            Ok(()) => eprintln!(
+                "{}",
+                format!(
+                    "  - restored branch {} and deleted {}",
+                    switch.original.as_deref().unwrap_or_default(),
+                    switch.created
+                )
+                .yellow()
+            ),
+            Err(err) => eprintln!("{}", format!("❌ Failed to restore git branch: {:#}", err).red()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::RealFileSystem;
+    use crate::health::CheckStatus;
+    use crate::manifest::TutorialType;
+    use crate::runner::{CommandOutput, RecordingRunner};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn manifest(slug: &str) -> Manifest {
+        Manifest::new(slug, "My Tutorial", TutorialType::Sdk)
+    }
+
+    fn repo_root() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("tutorials")).unwrap();
+        fs::write(temp_dir.path().join("versions.yml"), "versions:\n  rust: \"1.86\"\n").unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_creates_tutorial_without_npm_or_git() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let report = create_tutorial(&ctx, &manifest("my-tutorial"), &CreateOptions::default()).unwrap();
+        // The fake runner never lets npm write package.json
+        let failed: Vec<_> = report
+            .checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .map(|check| check.name.as_str())
+            .collect();
+        assert_eq!(failed, vec!["package.json"]);
+
+        let tutorial_dir = temp_dir.path().join("tutorials/my-tutorial");
+        assert!(tutorial_dir.join("tutorial.yml").is_file());
+        assert!(tutorial_dir.join("vitest.config.ts").is_file());
+        assert_eq!(
+            runner.command_lines(),
+            vec![
+                "git symbolic-ref --short HEAD",
+                "git checkout -b feat/tutorial-my-tutorial",
+                "npm init -y",
+                "npm pkg set name=my-tutorial type=module",
+                "npm i -D vitest@^3.2.4 typescript@^5.9.2 ts-node@^10.9.2 @types/node@^24.3.1",
+                "npm i @polkadot/api@^16.4.6 ws@^8.18.3",
+                "npm pkg set scripts.test=vitest run scripts.test:watch=vitest",
+            ]
+        );
+        let npm_dirs: Vec<_> = runner.commands().into_iter().skip(2).map(|c| c.cwd.unwrap()).collect();
+        assert!(npm_dirs.iter().all(|dir| *dir == tutorial_dir));
+    }
+
+    #[test]
+    fn test_npm_failure_is_propagated_with_stderr() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new()
+            .respond("npm i -D", CommandOutput::failure(1, "npm ERR! network request failed"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let err = create_tutorial(&ctx, &manifest("my-tutorial"), &CreateOptions::default()).unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains("Failed to install dev dependencies"));
+        assert!(message.contains("npm ERR! network request failed"));
+        // Nothing after the failing step ran
+        assert!(!runner.command_lines().iter().any(|c| c.starts_with("npm i @polkadot/api")));
+    }
+
+    #[test]
+    fn test_failure_rolls_back_files_and_branch() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new()
+            .respond("git symbolic-ref", CommandOutput::success("main\n"))
+            .respond("npm i", CommandOutput::failure(1, "offline"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        assert!(create_tutorial(&ctx, &manifest("my-tutorial"), &CreateOptions::default()).is_err());
+
+        assert!(temp_dir.path().join("tutorials").is_dir(), "pre-existing directory is kept");
+        assert!(!temp_dir.path().join("tutorials/my-tutorial").exists());
+        let commands = runner.command_lines();
+        assert_eq!(
+            commands[commands.len() - 2..],
+            ["git checkout main", "git branch -D feat/tutorial-my-tutorial"]
+        );
+    }
+
+    #[test]
+    fn test_keep_on_failure_leaves_files() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new().respond("npm i", CommandOutput::failure(1, "offline"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            keep_on_failure: true,
+            ..Default::default()
+        };
+
+        assert!(create_tutorial(&ctx, &manifest("my-tutorial"), &options).is_err());
+        assert!(temp_dir.path().join("tutorials/my-tutorial/tutorial.yml").is_file());
+        assert!(!runner.command_lines().iter().any(|c| c.starts_with("git branch -D")));
+    }
+
+    #[test]
+    fn test_git_failure_only_warns() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new()
+            .respond("git", CommandOutput::failure(128, "fatal: not a git repository"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        create_tutorial(&ctx, &manifest("my-tutorial"), &CreateOptions::default()).unwrap();
+        assert!(fs::metadata(temp_dir.path().join("tutorials/my-tutorial")).is_ok());
+    }
+
+    #[test]
+    fn test_git_failure_with_git_options_rolls_back() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new()
+            .respond("git checkout -b", CommandOutput::failure(128, "fatal: branch already exists"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            offline: true,
+            git: GitOptions {
+                require_clean: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let err = create_tutorial(&ctx, &manifest("my-tutorial"), &options).unwrap_err();
+        assert!(err.to_string().contains("branch already exists"));
+        assert!(!temp_dir.path().join("tutorials/my-tutorial").exists());
+    }
+
+    #[test]
+    fn test_commit_option_commits_on_prefixed_branch() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            offline: true,
+            git: GitOptions {
+                branch_prefix: "tutorial/".to_string(),
+                require_clean: true,
+                commit: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        create_tutorial(&ctx, &manifest("my-tutorial"), &options).unwrap();
+
+        assert_eq!(
+            runner.command_lines(),
+            vec![
+                "git status --porcelain",
+                "git symbolic-ref --short HEAD",
+                "git checkout -b tutorial/my-tutorial",
+                "git add -- tutorials/my-tutorial",
+                "git commit -m feat(tutorial): add my-tutorial -- tutorials/my-tutorial",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dirty_tree_stops_before_anything_is_created() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new().respond("git status", CommandOutput::success("?? notes.txt\n"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            git: GitOptions {
+                require_clean: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(create_tutorial(&ctx, &manifest("my-tutorial"), &options).is_err());
+        assert!(!temp_dir.path().join("tutorials/my-tutorial").exists());
+        assert_eq!(runner.command_lines(), vec!["git status --porcelain"]);
+    }
+
+    #[test]
+    fn test_offline_mode_runs_no_npm() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            offline: true,
+            ..Default::default()
+        };
+
+        let report = create_tutorial(&ctx, &manifest("my-tutorial"), &options).unwrap();
+        assert!(!report.has_failures(), "{:?}", report);
+
+        assert!(runner.commands().iter().all(|c| c.program == "git"));
+        let package_json = temp_dir.path().join("tutorials/my-tutorial/package.json");
+        assert!(fs::read_to_string(package_json).unwrap().contains("\"vitest\": \"^3.2.4\""));
+    }
+
+    #[test]
+    fn test_template_selects_scaffold_set() {
+        let temp_dir = repo_root();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let options = CreateOptions {
+            offline: true,
+            ..Default::default()
+        };
+        let manifest = Manifest::new("my-contract", "My Contract", TutorialType::Contracts);
+
+        create_tutorial(&ctx, &manifest, &options).unwrap();
+
+        let tutorial_dir = temp_dir.path().join("tutorials/my-contract");
+        let written = Manifest::load(&tutorial_dir.join("tutorial.yml")).unwrap();
+        assert_eq!(written.tutorial_type.as_deref(), Some("contracts"));
+        let justfile = fs::read_to_string(tutorial_dir.join("justfile")).unwrap();
+        assert!(justfile.contains("cargo contract build --release"));
+        assert!(crate::validator::validate_tutorial(&tutorial_dir).is_empty());
+    }
+}
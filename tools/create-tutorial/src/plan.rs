@@ -0,0 +1,57 @@
+use colored::*;
+
+use crate::filesystem::{DryRunFileSystem, FileOperation};
+use crate::runner::{CommandSpec, RecordingRunner};
+
+/// Prints everything a dry run would have done
+pub fn print_plan(fs: &DryRunFileSystem, runner: &RecordingRunner) {
+    println!("\n{}", "📋 Dry run complete - nothing was written or executed".yellow());
+    print!("{}", render_plan(&fs.operations(), &runner.commands()));
+}
+
+fn render_plan(operations: &[FileOperation], commands: &[CommandSpec]) -> String {
+    let mut plan = String::from("\nDirectories:\n");
+    for op in operations {
+        if let FileOperation::CreateDir(path) = op {
+            plan += &format!("  {}/\n", path.display());
+        }
+    }
+
+    plan += "\nFiles:\n";
+    for op in operations {
+        if let FileOperation::WriteFile { path, size, sha256 } = op {
+            plan += &format!("  {}  {} bytes  sha256:{}\n", path.display(), size, sha256);
+        }
+    }
+
+    plan += "\nCommands:\n";
+    for command in commands {
+        plan += &format!("  $ {}\n", command);
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_render_plan_lists_every_section() {
+        let operations = vec![
+            FileOperation::CreateDir(PathBuf::from("tutorials/x/tests")),
+            FileOperation::WriteFile {
+                path: PathBuf::from("tutorials/x/README.md"),
+                size: 5,
+                sha256: "abc".to_string(),
+            },
+        ];
+        let commands = vec![CommandSpec::new("git").args(["checkout", "-b", "feat/tutorial-x"])];
+
+        let plan = render_plan(&operations, &commands);
+        assert!(plan.contains("  tutorials/x/tests/\n"));
+        assert!(plan.contains("  tutorials/x/README.md  5 bytes  sha256:abc\n"));
+        assert!(plan.contains("  $ git checkout -b feat/tutorial-x\n"));
+    }
+}
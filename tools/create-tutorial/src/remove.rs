@@ -0,0 +1,463 @@
+use anyhow::{bail, Result};
+use colored::*;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+
+use crate::context::Context;
+use crate::filesystem::FileSystem;
+use crate::scaffold::SCAFFOLD_BASE_FILE;
+use crate::versions::{override_key, VERSIONS_FILE};
+
+/// Directory under `tutorials/` that archived tutorials are moved to
+pub const ARCHIVE_DIR: &str = "_archive";
+
+/// Longest reference line printed before it is cut short
+const MAX_REFERENCE_WIDTH: usize = 100;
+
+/// Directories never searched for references
+const SKIPPED_DIRS: &[&str] = &[".git", "node_modules", "target", ARCHIVE_DIR];
+
+/// A relative path leaving its directory, e.g. `../../kitchensink-parachain`
+const RELATIVE_PATH: &str = r"(?:\.\./)+[A-Za-z0-9_.\-/]*";
+
+/// A line that mentions something `remove` does not touch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    pub path: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    pub text: String,
+}
+
+/// Every change `remove` makes, plus the references it leaves alone
+#[derive(Debug, PartialEq, Eq)]
+pub struct RemovePlan {
+    pub tutorial_dir: PathBuf,
+    /// Where the tutorial is moved instead of being deleted
+    pub archive_to: Option<PathBuf>,
+    /// `versions.yml` without the tutorial's override block, as (path, original, updated)
+    pub versions_edit: Option<(PathBuf, String, String)>,
+    /// Lines elsewhere in the repository that mention the tutorial
+    pub mentions: Vec<Reference>,
+    /// Lines in the tutorial pointing at paths outside it, e.g. a justfile
+    /// recipe running `cd ../../kitchensink-parachain`
+    pub outside_paths: Vec<Reference>,
+}
+
+/// Works out how to remove (or, with `archive`, move to
+/// `tutorials/_archive/`) tutorial `slug`.
+pub fn plan_remove(ctx: &Context, slug: &str, archive: bool) -> Result<RemovePlan> {
+    let tutorials_dir = ctx.root.join("tutorials");
+    let tutorial_dir = ctx.tutorial_dir(slug);
+    if !ctx.fs.is_dir(&tutorial_dir) {
+        bail!("Tutorial directory {} does not exist", tutorial_dir.display());
+    }
+
+    let archive_to = if archive {
+        let target = tutorials_dir.join(ARCHIVE_DIR).join(slug);
+        if ctx.fs.exists(&target) {
+            bail!("{} already exists", target.display());
+        }
+        Some(target)
+    } else {
+        None
+    };
+
+    let versions_path = ctx.root.join(VERSIONS_FILE);
+    let mut versions_edit = None;
+    if ctx.fs.exists(&versions_path) {
+        let original = ctx.fs.read_to_string(&versions_path)?;
+        let updated = strip_override_block(&original, &override_key(slug));
+        if updated != original {
+            versions_edit = Some((versions_path.clone(), original, updated));
+        }
+    }
+
+    let mention = Regex::new(&format!(
+        r"(^|[^A-Za-z0-9_-])({}|{})([^A-Za-z0-9_-]|$)",
+        regex::escape(slug),
+        regex::escape(&override_key(slug))
+    ))
+    .unwrap();
+    let mut mentions = Vec::new();
+    for path in text_files(ctx.fs, &ctx.root)? {
+        if path.starts_with(&tutorial_dir) || path == versions_path {
+            continue;
+        }
+        mentions.extend(matching_lines(ctx.fs, &path, |line| mention.is_match(line)));
+    }
+
+    let relative_path = Regex::new(RELATIVE_PATH).unwrap();
+    let mut outside_paths = Vec::new();
+    for path in text_files(ctx.fs, &tutorial_dir)? {
+        // The scaffold snapshot quotes the generated files; the files
+        // themselves are scanned already
+        if path.ends_with(SCAFFOLD_BASE_FILE) {
+            continue;
+        }
+        let dir = path.parent().unwrap_or(&tutorial_dir).to_path_buf();
+        outside_paths.extend(matching_lines(ctx.fs, &path, |line| {
+            relative_path
+                .find_iter(line)
+                .any(|target| !normalize(&dir.join(target.as_str())).starts_with(normalize(&tutorial_dir)))
+        }));
+    }
+
+    Ok(RemovePlan {
+        tutorial_dir,
+        archive_to,
+        versions_edit,
+        mentions,
+        outside_paths,
+    })
+}
+
+/// Removes the `key:` block from a YAML document: the key line and the
+/// indented lines below it. Everything else is kept byte for byte.
+fn strip_override_block(yaml: &str, key: &str) -> String {
+    let header = format!("{}:", key);
+    let mut result = String::with_capacity(yaml.len());
+    let mut in_block = false;
+
+    for line in yaml.split_inclusive('\n') {
+        if in_block {
+            if line.starts_with([' ', '\t']) {
+                continue;
+            }
+            in_block = false;
+        }
+        if line.trim_end() == header || line.starts_with(&format!("{} ", header)) {
+            in_block = true;
+            continue;
+        }
+        result.push_str(line);
+    }
+
+    result
+}
+
+/// Files under `dir`, sorted, skipping `SKIPPED_DIRS`. Paths are joined
+/// onto `dir`, so an empty `dir` (the current directory) gives relative paths.
+fn text_files(fs: &dyn FileSystem, dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        for path in fs.read_dir(&dir)? {
+            let skipped = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| SKIPPED_DIRS.contains(&name));
+            if !fs.is_dir(&path) {
+                files.push(path);
+            } else if !skipped {
+                pending.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Lines of `path` for which `matches` holds; binary files have none
+fn matching_lines(fs: &dyn FileSystem, path: &Path, matches: impl Fn(&str) -> bool) -> Vec<Reference> {
+    let Ok(content) = fs.read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| matches(line))
+        .map(|(index, line)| Reference {
+            path: path.to_path_buf(),
+            line: index + 1,
+            text: line.trim().to_string(),
+        })
+        .collect()
+}
+
+/// Resolves `.` and `..` without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Applies the plan: rewrites `versions.yml`, then moves or deletes the
+/// tutorial. If moving or deleting fails, `versions.yml` is restored.
+pub fn apply_remove(ctx: &Context, plan: &RemovePlan) -> Result<()> {
+    if let Some((path, _, updated)) = &plan.versions_edit {
+        ctx.fs.write(path, updated)?;
+    }
+
+    let result = match &plan.archive_to {
+        Some(target) => ctx
+            .fs
+            .create_dir_all(target.parent().unwrap())
+            .and_then(|_| ctx.fs.rename(&plan.tutorial_dir, target)),
+        None => ctx.fs.remove_dir_all(&plan.tutorial_dir),
+    };
+
+    if result.is_err() {
+        if let Some((path, original, _)) = &plan.versions_edit {
+            if let Err(err) = ctx.fs.write(path, original) {
+                eprintln!("{}", format!("❌ Failed to restore {}: {:#}", path.display(), err).red());
+            }
+        }
+    }
+
+    result
+}
+
+/// Prints the changes of a plan
+pub fn print_plan(plan: &RemovePlan) {
+    match &plan.archive_to {
+        Some(target) => println!(
+            "{}",
+            format!("  - moved {} -> {}", plan.tutorial_dir.display(), target.display()).cyan()
+        ),
+        None => println!("{}", format!("  - deleted {}", plan.tutorial_dir.display()).cyan()),
+    }
+    if let Some((path, _, _)) = &plan.versions_edit {
+        println!("{}", format!("  - removed override block from {}", path.display()).cyan());
+    }
+}
+
+/// Prints the references `remove` leaves in place, so they can be cleaned
+/// up by hand
+pub fn print_references(plan: &RemovePlan) {
+    if !plan.mentions.is_empty() {
+        println!("\n{}", "⚠️  Still mentioned elsewhere:".yellow());
+        for reference in &plan.mentions {
+            print_reference(reference);
+        }
+    }
+
+    if !plan.outside_paths.is_empty() {
+        let targets = outside_targets(&plan.outside_paths);
+        println!(
+            "\n{}",
+            "⚠️  The tutorial pointed at shared paths outside it; they were left in place:".yellow()
+        );
+        for reference in &plan.outside_paths {
+            print_reference(reference);
+        }
+        if !targets.is_empty() {
+            println!(
+                "{}",
+                format!(
+                    "ℹ️  Check whether other tutorials still need: {}",
+                    targets.into_iter().collect::<Vec<_>>().join(", ")
+                )
+                .cyan()
+            );
+        }
+    }
+}
+
+/// The relative paths the references point at, deduplicated and sorted
+fn outside_targets(references: &[Reference]) -> BTreeSet<String> {
+    let relative_path = Regex::new(RELATIVE_PATH).unwrap();
+    references
+        .iter()
+        .flat_map(|reference| relative_path.find_iter(&reference.text))
+        .map(|target| target.as_str().to_string())
+        .collect()
+}
+
+fn print_reference(reference: &Reference) {
+    let mut text: String = reference.text.chars().take(MAX_REFERENCE_WIDTH).collect();
+    if text.len() < reference.text.len() {
+        text.push('…');
+    }
+    println!("  {}:{}: {}", reference.path.display(), reference.line, text);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::{MemoryFileSystem, RealFileSystem};
+    use crate::runner::RecordingRunner;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const VERSIONS: &str = "\
+versions:
+  rust: \"1.86\"
+
+# Tutorial-specific overrides
+my_tutorial:
+  rust: \"1.88\"
+  # pinned for the demo
+  polkadot_omni_node: \"0.5.0\"
+other_tutorial:
+  rust: \"1.87\"
+";
+
+    fn repo_with_tutorial(slug: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let dir = root.join("tutorials").join(slug);
+        fs::create_dir_all(dir.join("tests")).unwrap();
+        fs::write(dir.join("tutorial.yml"), format!("slug: {}\n", slug)).unwrap();
+        fs::write(
+            dir.join("justfile"),
+            "run-zombienet:\n  cd ../../kitchensink-parachain && zombienet spawn\n\ntest:\n  cd tests && npm test\n",
+        )
+        .unwrap();
+        fs::write(root.join(VERSIONS_FILE), VERSIONS).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_strips_only_the_override_block() {
+        assert_eq!(
+            strip_override_block(VERSIONS, "my_tutorial"),
+            "versions:\n  rust: \"1.86\"\n\n# Tutorial-specific overrides\nother_tutorial:\n  rust: \"1.87\"\n"
+        );
+        assert_eq!(strip_override_block(VERSIONS, "my"), VERSIONS);
+    }
+
+    #[test]
+    fn test_remove_deletes_and_reports_references() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".github/workflows")).unwrap();
+        fs::write(
+            root.join(".github/workflows/ci.yml"),
+            "paths:\n  - tutorials/my-tutorial/**\n  - tutorials/my-tutorial-2/**\n",
+        )
+        .unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(root);
+        let plan = plan_remove(&ctx, "my-tutorial", false).unwrap();
+        assert_eq!(
+            plan.mentions,
+            vec![Reference {
+                path: root.join(".github/workflows/ci.yml"),
+                line: 2,
+                text: "- tutorials/my-tutorial/**".to_string(),
+            }]
+        );
+        assert_eq!(plan.outside_paths.len(), 1);
+        assert_eq!(plan.outside_paths[0].line, 2);
+
+        apply_remove(&ctx, &plan).unwrap();
+        assert!(!root.join("tutorials/my-tutorial").exists());
+        assert!(!fs::read_to_string(root.join(VERSIONS_FILE)).unwrap().contains("my_tutorial"));
+    }
+
+    #[test]
+    fn test_archive_moves_the_tutorial() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let root = temp_dir.path();
+
+        let plan = plan_remove(&ctx, "my-tutorial", true).unwrap();
+        apply_remove(&ctx, &plan).unwrap();
+
+        assert!(!root.join("tutorials/my-tutorial").exists());
+        assert!(root.join("tutorials/_archive/my-tutorial/tutorial.yml").is_file());
+
+        // Archived copies are neither taken again nor searched for references
+        fs::create_dir_all(root.join("tutorials/my-tutorial")).unwrap();
+        let err = plan_remove(&ctx, "my-tutorial", true).unwrap_err();
+        assert!(err.to_string().ends_with("already exists"));
+        assert!(plan_remove(&ctx, "my-tutorial", false).unwrap().mentions.is_empty());
+    }
+
+    #[test]
+    fn test_paths_inside_the_tutorial_are_not_reported() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let dir = temp_dir.path().join("tutorials/my-tutorial");
+        fs::write(dir.join("tests/helper.ts"), "import { start } from '../scripts/start';\n").unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let plan = plan_remove(&ctx, "my-tutorial", false).unwrap();
+        assert_eq!(plan.outside_paths.len(), 1);
+        assert_eq!(plan.outside_paths[0].path, dir.join("justfile"));
+    }
+
+    #[test]
+    fn test_scaffold_snapshot_is_not_scanned() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let dir = temp_dir.path().join("tutorials/my-tutorial");
+        fs::write(
+            dir.join(SCAFFOLD_BASE_FILE),
+            "{\"justfile\": \"run-zombienet:\\n  cd ../../kitchensink-parachain\\n\"}\n",
+        )
+        .unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let plan = plan_remove(&ctx, "my-tutorial", false).unwrap();
+        assert_eq!(plan.outside_paths.len(), 1);
+        assert_eq!(plan.outside_paths[0].path, dir.join("justfile"));
+    }
+
+    #[test]
+    fn test_remove_goes_through_the_context_filesystem() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("tutorials/my-tutorial/justfile"), "test:\n  cd ../../shared\n").unwrap();
+        fs.write(Path::new("tutorials/other/README.md"), "See my-tutorial first.\n").unwrap();
+        fs.write(Path::new(VERSIONS_FILE), VERSIONS).unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+
+        let plan = plan_remove(&ctx, "my-tutorial", true).unwrap();
+        assert_eq!(plan.mentions.len(), 1);
+        assert_eq!(plan.outside_paths.len(), 1);
+
+        apply_remove(&ctx, &plan).unwrap();
+        assert_eq!(
+            fs.files().into_keys().collect::<Vec<_>>(),
+            vec![
+                PathBuf::from("tutorials/_archive/my-tutorial/justfile"),
+                PathBuf::from("tutorials/other/README.md"),
+                PathBuf::from(VERSIONS_FILE),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outside_targets_are_cut_from_quoted_text() {
+        let reference = |text: &str| Reference {
+            path: PathBuf::from("package.json"),
+            line: 1,
+            text: text.to_string(),
+        };
+        let targets = outside_targets(&[
+            reference(r#""zombienet": "cd ../../kitchensink-parachain\nzombienet spawn","#),
+            reference("cd ../../kitchensink-parachain && zombienet spawn"),
+            reference("import { start } from '../shared/start';"),
+        ]);
+        assert_eq!(
+            targets.into_iter().collect::<Vec<_>>(),
+            vec!["../../kitchensink-parachain", "../shared/start"]
+        );
+    }
+
+    #[test]
+    fn test_missing_tutorial_is_an_error() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let err = plan_remove(&ctx, "other", false).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+    }
+}
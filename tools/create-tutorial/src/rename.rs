@@ -0,0 +1,572 @@
+use anyhow::{bail, Context as _, Result};
+use colored::*;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::context::Context;
+use crate::filesystem::FileSystem;
+use crate::git::GitOptions;
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::scaffold::{ScaffoldBase, SCAFFOLD_BASE_FILE};
+use crate::title::TitleDictionary;
+use crate::validator::is_valid_slug;
+use crate::versions::{override_key, VERSIONS_FILE};
+
+/// Files in the tutorial root the scaffold writes the slug into; files in
+/// `tests/` and `scripts/` are rewritten too
+const REWRITTEN_FILES: &[&str] = &[MANIFEST_FILE, "README.md", "justfile"];
+
+/// npm files whose package name is the slug
+const PACKAGE_FILES: &[&str] = &["package.json", "package-lock.json"];
+
+/// Every change `rename` makes, computed up front so nothing is touched
+/// until the whole rename is known to be possible
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RenamePlan {
+    /// Files rewritten in place (before any move), as (path, original, updated)
+    pub edits: Vec<(PathBuf, String, String)>,
+    /// Paths moved, in order; later moves may contain earlier ones
+    pub moves: Vec<(PathBuf, PathBuf)>,
+    /// Git branch renamed from the first to the second name
+    pub branch: Option<(String, String)>,
+}
+
+/// Works out how to rename tutorial `old` to `new`: its directory,
+/// `<slug>-code/`, `tests/<slug>-*` files, the places the scaffold put the
+/// slug (and the derived title) in its manifest, README, justfile, tests,
+/// scripts and scaffold snapshot, the npm package name, the `versions.yml`
+/// override key, and the `<prefix><slug>` git branch if it exists. Other
+/// text that happens to contain the slug, like `@types/node` in a tutorial
+/// called `node`, is left alone.
+pub fn plan_rename(ctx: &Context, old: &str, new: &str, git: &GitOptions) -> Result<RenamePlan> {
+    if !is_valid_slug(new) {
+        bail!("\"{}\" is not a valid slug", new);
+    }
+    let old_dir = ctx.tutorial_dir(old);
+    let new_dir = ctx.tutorial_dir(new);
+    if !ctx.fs.is_dir(&old_dir) {
+        bail!("Tutorial directory {} does not exist", old_dir.display());
+    }
+    if ctx.fs.exists(&new_dir) {
+        bail!("Tutorial \"{}\" already exists", new);
+    }
+
+    let mut plan = RenamePlan::default();
+
+    // The title is only rewritten when it was derived from the old slug
+    let titles = TitleDictionary::load(&ctx.root)?;
+    let manifest_path = old_dir.join(MANIFEST_FILE);
+    let old_title = titles.title(old);
+    let rename_title = ctx.fs.exists(&manifest_path)
+        && Manifest::from_yaml_str(&ctx.fs.read_to_string(&manifest_path)?)
+            .with_context(|| format!("Failed to parse {}", manifest_path.display()))?
+            .name
+            == old_title;
+    let new_title = titles.title(new);
+
+    let tests_dir = old_dir.join("tests");
+    let test_files = files_in(ctx.fs, &tests_dir)?;
+    let mut tokens = scaffold_tokens(old, new);
+    for path in &test_files {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if let Some(rest) = name.strip_prefix(&format!("{}-", old)) {
+            let renamed = format!("{}-{}", new, rest);
+            plan.moves.push((path.clone(), tests_dir.join(&renamed)));
+            tokens.push((name.to_string(), renamed));
+        }
+    }
+    let rewrite = |path: &Path, text: &str| {
+        let updated = replace_tokens(text, &tokens);
+        if rename_title {
+            replace_title(path, &updated, &old_title, &new_title)
+        } else {
+            updated
+        }
+    };
+
+    let mut rewritten: Vec<PathBuf> = REWRITTEN_FILES.iter().map(|file| old_dir.join(file)).collect();
+    rewritten.extend(test_files.iter().cloned());
+    rewritten.extend(files_in(ctx.fs, &old_dir.join("scripts"))?);
+    for path in rewritten {
+        if !ctx.fs.exists(&path) || ctx.fs.is_dir(&path) {
+            continue;
+        }
+        let original = ctx.fs.read_to_string(&path)?;
+        let updated = rewrite(&path, &original);
+        if updated != original {
+            plan.edits.push((path, original, updated));
+        }
+    }
+
+    for file in PACKAGE_FILES {
+        let path = old_dir.join(file);
+        if !ctx.fs.exists(&path) {
+            continue;
+        }
+        let original = ctx.fs.read_to_string(&path)?;
+        let updated = rename_package(&original, old, new).with_context(|| format!("Failed to parse {}", path.display()))?;
+        if updated != original {
+            plan.edits.push((path, original, updated));
+        }
+    }
+
+    let base_path = old_dir.join(SCAFFOLD_BASE_FILE);
+    if let Some(base) = ScaffoldBase::load(ctx.fs, &old_dir)? {
+        let original = ctx.fs.read_to_string(&base_path)?;
+        let files = base
+            .files
+            .iter()
+            .map(|(file, content)| (replace_tokens(file, &tokens), rewrite(Path::new(file), content)))
+            .collect();
+        let updated = ScaffoldBase { files, ..base }.to_json()?;
+        if updated != original {
+            plan.edits.push((base_path, original, updated));
+        }
+    }
+
+    let old_code = old_dir.join(format!("{}-code", old));
+    if ctx.fs.is_dir(&old_code) {
+        plan.moves.push((old_code, old_dir.join(format!("{}-code", new))));
+    }
+    plan.moves.push((old_dir, new_dir));
+
+    let versions_path = ctx.root.join(VERSIONS_FILE);
+    if ctx.fs.exists(&versions_path) {
+        let original = ctx.fs.read_to_string(&versions_path)?;
+        let top_level_key = |key: &str| Regex::new(&format!(r"(?m)^{}:", regex::escape(key))).unwrap();
+        if top_level_key(&override_key(new)).is_match(&original) {
+            bail!(
+                "{} already has a top-level \"{}\" key; remove it before renaming",
+                versions_path.display(),
+                override_key(new)
+            );
+        }
+        let key_regex = top_level_key(&override_key(old));
+        let updated = key_regex
+            .replace(&original, format!("{}:", override_key(new)))
+            .into_owned();
+        if updated != original {
+            plan.edits.push((versions_path, original, updated));
+        }
+    }
+
+    let old_branch = git.branch_name(old);
+    let branch_exists = git.enabled
+        && ctx
+            .runner
+            .run(&ctx.command("git").args(["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", old_branch)]))
+            .is_ok_and(|output| output.success);
+    if branch_exists {
+        plan.branch = Some((old_branch, git.branch_name(new)));
+    }
+
+    Ok(plan)
+}
+
+/// Regular files directly inside `dir`, sorted; none if it doesn't exist
+fn files_in(fs: &dyn FileSystem, dir: &Path) -> Result<Vec<PathBuf>> {
+    if !fs.is_dir(dir) {
+        return Ok(Vec::new());
+    }
+    Ok(fs.read_dir(dir)?.into_iter().filter(|path| !fs.is_dir(path)).collect())
+}
+
+/// A change already made by `apply_rename`, kept so it can be undone
+enum Applied<'a> {
+    Edit(&'a Path, &'a str),
+    Move(&'a Path, &'a Path),
+}
+
+/// Applies the plan. If any step fails, the steps already taken are undone
+/// in reverse order and the error is returned.
+pub fn apply_rename(ctx: &Context, plan: &RenamePlan) -> Result<()> {
+    let mut applied = Vec::new();
+    let result = apply_steps(ctx, plan, &mut applied);
+
+    if result.is_err() {
+        for step in applied.into_iter().rev() {
+            let undo = match step {
+                Applied::Edit(path, original) => ctx.fs.write(path, original),
+                Applied::Move(from, to) => ctx.fs.rename(to, from),
+            };
+            if let Err(err) = undo {
+                eprintln!("{}", format!("❌ Failed to undo rename step: {:#}", err).red());
+            }
+        }
+    }
+
+    result
+}
+
+fn apply_steps<'a>(ctx: &Context, plan: &'a RenamePlan, applied: &mut Vec<Applied<'a>>) -> Result<()> {
+    for (path, original, updated) in &plan.edits {
+        ctx.fs.write(path, updated)?;
+        applied.push(Applied::Edit(path, original));
+    }
+    for (from, to) in &plan.moves {
+        ctx.fs.rename(from, to)?;
+        applied.push(Applied::Move(from, to));
+    }
+    if let Some((old_branch, new_branch)) = &plan.branch {
+        ctx.runner
+            .run_checked(&ctx.command("git").args(["branch", "-m", old_branch, new_branch]))?;
+    }
+    Ok(())
+}
+
+/// Prints the changes of a plan
+pub fn print_plan(plan: &RenamePlan) {
+    for (path, _, _) in &plan.edits {
+        println!("{}", format!("  - updated {}", path.display()).cyan());
+    }
+    for (from, to) in &plan.moves {
+        println!("{}", format!("  - moved {} -> {}", from.display(), to.display()).cyan());
+    }
+    if let Some((old_branch, new_branch)) = &plan.branch {
+        println!("{}", format!("  - renamed branch {} -> {}", old_branch, new_branch).cyan());
+    }
+}
+
+/// The text the scaffold derives from the slug, paired with what it
+/// becomes: the tutorial path, the code directory, the test crate name,
+/// the test suite name and the doc comment of Rust tests
+fn scaffold_tokens(old: &str, new: &str) -> Vec<(String, String)> {
+    [
+        "slug: {}",
+        "tutorials/{}",
+        "{}-code",
+        "{}-tests",
+        "'{} e2e'",
+        "tests for {}",
+    ]
+    .iter()
+    .map(|pattern| (pattern.replace("{}", old), pattern.replace("{}", new)))
+    .collect()
+}
+
+/// Replaces each token where it stands on its own: not glued to letters,
+/// digits, underscores or dashes, so `tutorials/node` is replaced in a
+/// tutorial called `node` but `tutorials/node-api` is not
+fn replace_tokens(text: &str, tokens: &[(String, String)]) -> String {
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let mut text = text.to_string();
+
+    for (old, new) in tokens {
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some(index) = rest.find(old.as_str()) {
+            let before = rest[..index]
+                .chars()
+                .next_back()
+                .or_else(|| result.chars().next_back());
+            let after = rest[index + old.len()..].chars().next();
+            result.push_str(&rest[..index]);
+            if before.is_some_and(is_token_char) || after.is_some_and(is_token_char) {
+                result.push_str(old);
+            } else {
+                result.push_str(new);
+            }
+            rest = &rest[index + old.len()..];
+        }
+        result.push_str(rest);
+        text = result;
+    }
+
+    text
+}
+
+/// Renames the npm package in a package.json or package-lock.json: the
+/// top-level `name` and, in a lockfile, `packages[""].name`. Dependencies
+/// are left alone even when they contain the slug.
+fn rename_package(json: &str, old: &str, new: &str) -> Result<String> {
+    let mut package: serde_json::Value = serde_json::from_str(json)?;
+    let mut renamed = false;
+    for pointer in ["/name", "/packages//name"] {
+        if let Some(name) = package.pointer_mut(pointer).filter(|name| *name == old) {
+            *name = serde_json::Value::from(new);
+            renamed = true;
+        }
+    }
+    if !renamed {
+        return Ok(json.to_string());
+    }
+
+    let mut updated = serde_json::to_string_pretty(&package)?;
+    if json.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// Replaces the derived title in the manifest's `name` and in README headings
+fn replace_title(path: &Path, text: &str, old_title: &str, new_title: &str) -> String {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    let is_title_line = |line: &str| match file_name {
+        MANIFEST_FILE => line.starts_with("name:"),
+        "README.md" => line.starts_with('#'),
+        _ => false,
+    };
+
+    let mut result: String = text
+        .lines()
+        .map(|line| {
+            if is_title_line(line) {
+                line.replace(old_title, new_title)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if text.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::{MemoryFileSystem, RealFileSystem};
+    use crate::manifest::TutorialType;
+    use crate::pipeline::CreateOptions;
+    use crate::runner::{CommandOutput, RecordingRunner};
+    use crate::upgrade::{self, FileStatus};
+    use crate::{bootstrap, scaffold};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn repo_with_tutorial(slug: &str) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("tutorials").join(slug);
+        fs::create_dir_all(dir.join("tests")).unwrap();
+        fs::create_dir_all(dir.join(format!("{}-code", slug))).unwrap();
+        fs::write(
+            dir.join(MANIFEST_FILE),
+            Manifest::new(slug, &TitleDictionary::builtin().title(slug), TutorialType::Sdk)
+                .to_yaml()
+                .unwrap(),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("README.md"),
+            format!("# {}\n\ncd tutorials/{}\n", TitleDictionary::builtin().title(slug), slug),
+        )
+        .unwrap();
+        fs::write(dir.join("package.json"), format!("{{\n  \"name\": \"{}\"\n}}\n", slug)).unwrap();
+        fs::create_dir_all(dir.join("scripts")).unwrap();
+        fs::write(dir.join("scripts/start.sh"), format!("./tutorials/{}/scripts/build.sh\n", slug)).unwrap();
+        fs::write(
+            dir.join("tests").join(format!("{}-e2e.test.ts", slug)),
+            format!("describe('{} e2e', () => {{}});\n", slug),
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join(VERSIONS_FILE),
+            format!("versions:\n  rust: \"1.86\"\n\n# keep\n{}:\n  rust: \"1.88\"\n", override_key(slug)),
+        )
+        .unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_replace_tokens_respects_boundaries() {
+        let tokens = scaffold_tokens("old", "new");
+        assert_eq!(
+            replace_tokens("cd tutorials/old && ls old-code/ tutorials/old-api golden old", &tokens),
+            "cd tutorials/new && ls new-code/ tutorials/old-api golden old"
+        );
+        assert_eq!(replace_tokens("slug: old\nslug: older\n", &tokens), "slug: new\nslug: older\n");
+    }
+
+    #[test]
+    fn test_rename_package_only_touches_the_package_name() {
+        let lock = r#"{
+  "name": "node",
+  "packages": {
+    "": {
+      "name": "node",
+      "devDependencies": {
+        "@types/node": "^24.3.1"
+      }
+    },
+    "node_modules/@types/node": {
+      "version": "24.3.1"
+    }
+  }
+}
+"#;
+        assert_eq!(
+            rename_package(lock, "node", "chain").unwrap(),
+            lock.replace(r#""name": "node""#, r#""name": "chain""#)
+        );
+        assert_eq!(rename_package(r#"{"name":"other"}"#, "node", "chain").unwrap(), r#"{"name":"other"}"#);
+    }
+
+    #[test]
+    fn test_slug_shared_with_dependencies_is_renamed_safely() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(VERSIONS_FILE), "").unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let manifest = Manifest::new("node", "Node", TutorialType::Sdk);
+        let options = CreateOptions {
+            offline: true,
+            ..Default::default()
+        };
+        scaffold::scaffold_structure(&ctx, &manifest, &Default::default()).unwrap();
+        bootstrap::bootstrap_tests(&ctx, &manifest, &options, &Default::default()).unwrap();
+
+        let plan = plan_rename(&ctx, "node", "chain", &GitOptions::default()).unwrap();
+        apply_rename(&ctx, &plan).unwrap();
+
+        let dir = ctx.tutorial_dir("chain");
+        let package_json = fs::read_to_string(dir.join("package.json")).unwrap();
+        assert!(package_json.contains(r#""name": "chain""#));
+        assert!(package_json.contains("@types/node") && package_json.contains("ts-node"));
+        let justfile = fs::read_to_string(dir.join("justfile")).unwrap();
+        assert!(justfile.contains("install-omni-node.sh") && !justfile.contains("omni-chain"));
+        let test = fs::read_to_string(dir.join("tests/chain-e2e.test.ts")).unwrap();
+        assert!(test.contains("'node:net'") && test.contains("describe('chain e2e'"));
+
+        // The snapshot follows the rename, so the tutorial is up to date
+        let plan = upgrade::plan_upgrade(&ctx, "chain").unwrap();
+        assert!(plan.files.iter().all(|file| file.status == FileStatus::UpToDate), "{:?}", plan.files);
+    }
+
+    #[test]
+    fn test_rename_rewrites_every_reference() {
+        let temp_dir = repo_with_tutorial("add-nft-pallet");
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let plan = plan_rename(&ctx, "add-nft-pallet", "mint-nfts", &GitOptions::default()).unwrap();
+        apply_rename(&ctx, &plan).unwrap();
+
+        let root = temp_dir.path();
+        let dir = root.join("tutorials/mint-nfts");
+        assert!(!root.join("tutorials/add-nft-pallet").exists());
+        assert!(dir.join("mint-nfts-code").is_dir());
+        assert!(dir.join("tests/mint-nfts-e2e.test.ts").is_file());
+
+        let manifest = Manifest::load(&dir.join(MANIFEST_FILE)).unwrap();
+        assert_eq!(manifest.slug, "mint-nfts");
+        assert_eq!(manifest.name, "Mint NFTs");
+        assert_eq!(manifest.project_dir(), "mint-nfts-code");
+        assert!(manifest.validate(&RealFileSystem, &dir).is_empty());
+
+        assert_eq!(fs::read_to_string(dir.join("README.md")).unwrap(), "# Mint NFTs\n\ncd tutorials/mint-nfts\n");
+        assert!(fs::read_to_string(dir.join("package.json")).unwrap().contains("\"name\": \"mint-nfts\""));
+        assert_eq!(
+            fs::read_to_string(dir.join("scripts/start.sh")).unwrap(),
+            "./tutorials/mint-nfts/scripts/build.sh\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(VERSIONS_FILE)).unwrap(),
+            "versions:\n  rust: \"1.86\"\n\n# keep\nmint_nfts:\n  rust: \"1.88\"\n"
+        );
+    }
+
+    #[test]
+    fn test_custom_title_is_kept() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let manifest_path = temp_dir.path().join("tutorials/my-tutorial").join(MANIFEST_FILE);
+        let custom = fs::read_to_string(&manifest_path).unwrap().replace("name: My Tutorial", "name: Custom");
+        fs::write(&manifest_path, custom).unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let plan = plan_rename(&ctx, "my-tutorial", "other", &GitOptions::default()).unwrap();
+        apply_rename(&ctx, &plan).unwrap();
+
+        let manifest = Manifest::load(&temp_dir.path().join("tutorials/other").join(MANIFEST_FILE)).unwrap();
+        assert_eq!(manifest.name, "Custom");
+    }
+
+    #[test]
+    fn test_branch_failure_undoes_everything() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let before = fs::read_to_string(temp_dir.path().join(VERSIONS_FILE)).unwrap();
+        let runner = RecordingRunner::new()
+            .respond("git branch -m", CommandOutput::failure(128, "branch is locked"));
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let plan = plan_rename(&ctx, "my-tutorial", "other", &GitOptions::default()).unwrap();
+        assert_eq!(
+            plan.branch,
+            Some(("feat/tutorial-my-tutorial".to_string(), "feat/tutorial-other".to_string()))
+        );
+        assert!(apply_rename(&ctx, &plan).is_err());
+
+        let dir = temp_dir.path().join("tutorials/my-tutorial");
+        assert!(dir.join("my-tutorial-code").is_dir());
+        assert!(dir.join("tests/my-tutorial-e2e.test.ts").is_file());
+        assert!(!temp_dir.path().join("tutorials/other").exists());
+        assert_eq!(fs::read_to_string(temp_dir.path().join(VERSIONS_FILE)).unwrap(), before);
+        assert_eq!(Manifest::load(&dir.join(MANIFEST_FILE)).unwrap().slug, "my-tutorial");
+    }
+
+    #[test]
+    fn test_rename_goes_through_the_context() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("tutorials/my-tutorial/README.md"), "cd tutorials/my-tutorial\n").unwrap();
+        fs.write(Path::new("tutorials/my-tutorial/tests/my-tutorial-e2e.test.ts"), "describe('my-tutorial e2e');\n")
+            .unwrap();
+        fs.write(Path::new("tutorials/my-tutorial/my-tutorial-code/Cargo.toml"), "").unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+
+        let plan = plan_rename(&ctx, "my-tutorial", "other", &GitOptions::default()).unwrap();
+        apply_rename(&ctx, &plan).unwrap();
+
+        assert_eq!(
+            fs.files(),
+            [
+                ("tutorials/other/README.md", "cd tutorials/other\n"),
+                ("tutorials/other/other-code/Cargo.toml", ""),
+                ("tutorials/other/tests/other-e2e.test.ts", "describe('other e2e');\n"),
+            ]
+            .into_iter()
+            .map(|(path, contents)| (PathBuf::from(path), contents.to_string()))
+            .collect()
+        );
+        assert_eq!(
+            runner.command_lines(),
+            vec![
+                "git rev-parse --verify --quiet refs/heads/feat/tutorial-my-tutorial",
+                "git branch -m feat/tutorial-my-tutorial feat/tutorial-other",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_existing_override_key() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        let versions_path = temp_dir.path().join(VERSIONS_FILE);
+        let versions = fs::read_to_string(&versions_path).unwrap() + "other:\n  rust: \"1.87\"\n";
+        fs::write(&versions_path, &versions).unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+
+        let err = plan_rename(&ctx, "my-tutorial", "other", &GitOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("already has a top-level \"other\" key"));
+        assert!(plan_rename(&ctx, "my-tutorial", "versions", &GitOptions::default()).is_err());
+        assert!(temp_dir.path().join("tutorials/my-tutorial").is_dir());
+        assert_eq!(fs::read_to_string(&versions_path).unwrap(), versions);
+    }
+
+    #[test]
+    fn test_rejects_invalid_or_taken_slugs() {
+        let temp_dir = repo_with_tutorial("my-tutorial");
+        fs::create_dir(temp_dir.path().join("tutorials/taken")).unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let git = GitOptions::default();
+
+        assert!(plan_rename(&ctx, "my-tutorial", "Bad Slug", &git).is_err());
+        assert!(plan_rename(&ctx, "my-tutorial", "taken", &git).is_err());
+        assert!(plan_rename(&ctx, "missing", "new-one", &git).is_err());
+    }
+}
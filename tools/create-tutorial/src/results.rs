@@ -0,0 +1,222 @@
+use anyhow::Result;
+use regex::Regex;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use crate::filesystem::FileSystem;
+use crate::harness::{PhaseStatus, TestReport};
+
+/// JSON summary of a tutorial's test run: the overall result, the phase
+/// that broke, and status, duration, exit code and log tail per phase
+pub fn summary_json(report: &TestReport) -> Result<String> {
+    let summary = serde_json::json!({
+        "tutorial": report.slug,
+        "passed": !report.has_failures(),
+        "failed_phase": report.failed_phase().map(|result| result.phase),
+        "duration_secs": report.duration().as_secs_f64(),
+        "phases": report.phases,
+    });
+    Ok(serde_json::to_string_pretty(&summary)?)
+}
+
+/// JUnit XML with one test suite per tutorial and one test case per phase,
+/// so CI dashboards show which tutorial broke and at which stage
+pub fn junit_xml(reports: &[TestReport]) -> String {
+    let count = |status: PhaseStatus| -> usize {
+        reports
+            .iter()
+            .flat_map(|report| &report.phases)
+            .filter(|result| result.status == status)
+            .count()
+    };
+    let tests: usize = reports.iter().map(|report| report.phases.len()).sum();
+    let time: f64 = reports.iter().map(|report| report.duration().as_secs_f64()).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"create-tutorial\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+        tests,
+        count(PhaseStatus::Failed),
+        count(PhaseStatus::Skipped),
+        time
+    );
+    for report in reports {
+        let count = |status: PhaseStatus| report.phases.iter().filter(|result| result.status == status).count();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{:.3}\">",
+            escape(&report.slug),
+            report.phases.len(),
+            count(PhaseStatus::Failed),
+            count(PhaseStatus::Skipped),
+            report.duration().as_secs_f64()
+        );
+        for result in &report.phases {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+                escape(&report.slug),
+                result.phase.as_str(),
+                result.duration.as_secs_f64()
+            );
+            match result.status {
+                PhaseStatus::Passed if result.log_tail.is_empty() => xml += "/>\n",
+                PhaseStatus::Passed => {
+                    let _ = write!(xml, ">\n      <system-out>{}</system-out>\n    </testcase>\n", escape(&result.log_tail));
+                }
+                PhaseStatus::Skipped => {
+                    let _ = write!(
+                        xml,
+                        ">\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                        escape(&result.message)
+                    );
+                }
+                PhaseStatus::Failed => {
+                    let kind = match result.exit_code {
+                        Some(code) => format!("exit code {}", code),
+                        None => "error".to_string(),
+                    };
+                    let _ = write!(
+                        xml,
+                        ">\n      <failure message=\"{}\" type=\"{}\">{}</failure>\n    </testcase>\n",
+                        escape(&result.message),
+                        kind,
+                        escape(&result.log_tail)
+                    );
+                }
+            }
+        }
+        xml += "  </testsuite>\n";
+    }
+    xml += "</testsuites>\n";
+    xml
+}
+
+/// Writes `<slug>.json` (see [`summary_json`]) and `<slug>.xml` (JUnit)
+/// into `dir`, returning their paths
+pub fn write_reports(fs: &dyn FileSystem, dir: &Path, report: &TestReport) -> Result<Vec<PathBuf>> {
+    fs.create_dir_all(dir)?;
+    let json = dir.join(format!("{}.json", report.slug));
+    fs.write(&json, &summary_json(report)?)?;
+    let xml = dir.join(format!("{}.xml", report.slug));
+    fs.write(&xml, &junit_xml(std::slice::from_ref(report)))?;
+    Ok(vec![json, xml])
+}
+
+/// Escapes text for XML attributes and content, dropping terminal colour
+/// codes and other control characters XML can't hold
+fn escape(text: &str) -> String {
+    let ansi = Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").unwrap();
+    let mut escaped = String::with_capacity(text.len());
+    for c in ansi.replace_all(text, "").chars() {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            '\'' => escaped += "&apos;",
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::MemoryFileSystem;
+    use crate::harness::{Phase, PhaseResult};
+    use std::time::Duration;
+
+    fn phase(phase: Phase, status: PhaseStatus, millis: u64, exit_code: Option<i32>, message: &str, log_tail: &str) -> PhaseResult {
+        PhaseResult {
+            phase,
+            status,
+            duration: Duration::from_millis(millis),
+            exit_code,
+            message: message.to_string(),
+            log_tail: log_tail.to_string(),
+        }
+    }
+
+    fn failed_report() -> TestReport {
+        let mut report = TestReport::new("my-tutorial");
+        report.phases = vec![
+            phase(Phase::Build, PhaseStatus::Passed, 1500, Some(0), "cargo build --release", ""),
+            phase(Phase::ChainSpec, PhaseStatus::Passed, 250, Some(0), "chain_spec.json", "done"),
+            phase(Phase::Node, PhaseStatus::Passed, 3000, None, "RPC up on port 9944", ""),
+            phase(
+                Phase::Tests,
+                PhaseStatus::Failed,
+                2000,
+                Some(1),
+                "`npx vitest run` failed with exit code 1",
+                "\x1b[31mexpected 1 < 2\x1b[0m",
+            ),
+            phase(Phase::Teardown, PhaseStatus::Passed, 10, None, "stopped the node", ""),
+        ];
+        report
+    }
+
+    #[test]
+    fn test_summary_json_names_failed_phase() {
+        let json: serde_json::Value = serde_json::from_str(&summary_json(&failed_report()).unwrap()).unwrap();
+
+        assert_eq!(json["tutorial"], "my-tutorial");
+        assert_eq!(json["passed"], false);
+        assert_eq!(json["failed_phase"], "tests");
+        assert_eq!(json["duration_secs"], 6.76);
+        assert_eq!(json["phases"][1]["phase"], "chain-spec");
+        assert_eq!(json["phases"][2]["exit_code"], serde_json::Value::Null);
+        assert_eq!(json["phases"][3]["status"], "failed");
+        assert_eq!(json["phases"][3]["exit_code"], 1);
+        assert_eq!(json["phases"][3]["duration_secs"], 2.0);
+    }
+
+    #[test]
+    fn test_junit_has_a_case_per_phase() {
+        let mut skipped = TestReport::new("other");
+        skipped.phases = vec![
+            phase(Phase::Build, PhaseStatus::Failed, 100, None, "Failed to run `sh`", ""),
+            phase(Phase::Tests, PhaseStatus::Skipped, 0, None, "an earlier phase failed", ""),
+        ];
+        let xml = junit_xml(&[failed_report(), skipped]);
+
+        assert!(xml.contains(
+            "<testsuites name=\"create-tutorial\" tests=\"7\" failures=\"2\" skipped=\"1\" time=\"6.860\">"
+        ));
+        assert!(xml.contains(
+            "<testsuite name=\"my-tutorial\" tests=\"5\" failures=\"1\" errors=\"0\" skipped=\"0\" time=\"6.760\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"my-tutorial\" name=\"build\" time=\"1.500\"/>"));
+        assert!(xml.contains("<system-out>done</system-out>"));
+        assert!(xml.contains(
+            "<failure message=\"`npx vitest run` failed with exit code 1\" type=\"exit code 1\">expected 1 &lt; 2</failure>"
+        ));
+        assert!(xml.contains("<failure message=\"Failed to run `sh`\" type=\"error\"></failure>"));
+        assert!(xml.contains("<skipped message=\"an earlier phase failed\"/>"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a<b & \"c\"\x07"), "a&lt;b &amp; &quot;c&quot;");
+        assert_eq!(escape("\x1b[1;32mok\x1b[0m\n"), "ok\n");
+    }
+
+    #[test]
+    fn test_write_reports_per_tutorial() {
+        let fs = MemoryFileSystem::new();
+        let paths = write_reports(&fs, Path::new("reports"), &failed_report()).unwrap();
+
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("reports/my-tutorial.json"), PathBuf::from("reports/my-tutorial.xml")]
+        );
+        let files = fs.files();
+        assert!(files[&paths[0]].contains("\"failed_phase\": \"tests\""));
+        assert!(files[&paths[1]].starts_with("<?xml"));
+    }
+}
@@ -0,0 +1,389 @@
+use anyhow::{bail, Context as _, Result};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+
+/// An external command: program, arguments, working directory and extra
+/// environment variables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandSpec {
+    pub program: String,
+    pub args: Vec<String>,
+    pub cwd: Option<PathBuf>,
+    pub envs: Vec<(String, String)>,
+}
+
+impl CommandSpec {
+    pub fn new(program: &str) -> Self {
+        CommandSpec {
+            program: program.to_string(),
+            args: Vec::new(),
+            cwd: None,
+            envs: Vec::new(),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn current_dir(mut self, dir: &Path) -> Self {
+        self.cwd = Some(dir.to_path_buf());
+        self
+    }
+
+    pub fn env(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.envs.push((key.to_string(), value.into()));
+        self
+    }
+}
+
+impl fmt::Display for CommandSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(cwd) = &self.cwd {
+            write!(f, "(cd {}) ", cwd.display())?;
+        }
+        for (key, value) in &self.envs {
+            write!(f, "{}={} ", key, value)?;
+        }
+        f.write_str(&self.program)?;
+        for arg in &self.args {
+            if arg.is_empty() || arg.contains(char::is_whitespace) {
+                write!(f, " '{}'", arg)?;
+            } else {
+                write!(f, " {}", arg)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Result of running a command
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub success: bool,
+    pub code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl CommandOutput {
+    pub fn success(stdout: &str) -> Self {
+        CommandOutput {
+            success: true,
+            code: Some(0),
+            stdout: stdout.to_string(),
+            stderr: String::new(),
+        }
+    }
+
+    pub fn failure(code: i32, stderr: &str) -> Self {
+        CommandOutput {
+            success: false,
+            code: Some(code),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+        }
+    }
+}
+
+/// A process left running in the background, such as a node
+pub trait BackgroundProcess {
+    /// Whether the process has not exited yet
+    fn is_running(&mut self) -> bool;
+
+    /// Stops the process and waits for it to exit
+    fn kill(&mut self) -> Result<()>;
+}
+
+/// Runs external commands on behalf of the creation steps
+pub trait CommandRunner {
+    fn run(&self, command: &CommandSpec) -> Result<CommandOutput>;
+
+    /// Starts the command without waiting for it, sending its stdout and
+    /// stderr to `log`
+    fn spawn(&self, command: &CommandSpec, log: &Path) -> Result<Box<dyn BackgroundProcess>>;
+
+    /// Runs the command and turns a non-zero exit into an error carrying its stderr
+    fn run_checked(&self, command: &CommandSpec) -> Result<CommandOutput> {
+        let output = self.run(command)?;
+        if !output.success {
+            let status = match output.code {
+                Some(code) => format!("exit code {}", code),
+                None => "a signal".to_string(),
+            };
+            let stderr = output.stderr.trim();
+            if stderr.is_empty() {
+                bail!("`{}` failed with {}", command, status);
+            }
+            bail!("`{}` failed with {}:\n{}", command, status, stderr);
+        }
+        Ok(output)
+    }
+}
+
+/// Spawns real processes
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, command: &CommandSpec) -> Result<CommandOutput> {
+        let mut process = Command::new(&command.program);
+        process.args(&command.args);
+        if let Some(cwd) = &command.cwd {
+            process.current_dir(cwd);
+        }
+        process.envs(command.envs.iter().map(|(key, value)| (key, value)));
+
+        let output = process
+            .output()
+            .with_context(|| format!("Failed to run `{}`", command))?;
+
+        Ok(CommandOutput {
+            success: output.status.success(),
+            code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        })
+    }
+
+    fn spawn(&self, command: &CommandSpec, log: &Path) -> Result<Box<dyn BackgroundProcess>> {
+        let stdout = File::create(log).with_context(|| format!("Failed to create {}", log.display()))?;
+        let stderr = stdout.try_clone()?;
+
+        let mut process = Command::new(&command.program);
+        process
+            .args(&command.args)
+            .stdin(Stdio::null())
+            .stdout(stdout)
+            .stderr(stderr);
+        if let Some(cwd) = &command.cwd {
+            process.current_dir(cwd);
+        }
+        process.envs(command.envs.iter().map(|(key, value)| (key, value)));
+
+        let child = process
+            .spawn()
+            .with_context(|| format!("Failed to run `{}`", command))?;
+        Ok(Box::new(SystemProcess(child)))
+    }
+}
+
+/// A child process spawned by [`SystemRunner`]
+struct SystemProcess(Child);
+
+impl BackgroundProcess for SystemProcess {
+    fn is_running(&mut self) -> bool {
+        matches!(self.0.try_wait(), Ok(None))
+    }
+
+    fn kill(&mut self) -> Result<()> {
+        if self.is_running() {
+            self.0.kill().context("Failed to stop process")?;
+        }
+        self.0.wait().context("Failed to wait for process")?;
+        Ok(())
+    }
+}
+
+/// Records commands instead of running them. Every command succeeds with
+/// empty output unless a scripted response matches it. Spawned commands keep
+/// running until killed, unless their scripted response is a failure.
+#[derive(Default)]
+pub struct RecordingRunner {
+    commands: RefCell<Vec<CommandSpec>>,
+    responses: Vec<(String, CommandOutput)>,
+    running: Rc<Cell<usize>>,
+}
+
+impl RecordingRunner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scripts the output for commands whose leading words (program, then
+    /// arguments) equal the words of `prefix`. The first matching response wins.
+    pub fn respond(mut self, prefix: &str, output: CommandOutput) -> Self {
+        self.responses.push((prefix.to_string(), output));
+        self
+    }
+
+    pub fn commands(&self) -> Vec<CommandSpec> {
+        self.commands.borrow().clone()
+    }
+
+    /// Recorded commands rendered as `program arg...`
+    pub fn command_lines(&self) -> Vec<String> {
+        self.commands.borrow().iter().map(command_line).collect()
+    }
+
+    /// Number of spawned processes that have not been killed
+    pub fn running_processes(&self) -> usize {
+        self.running.get()
+    }
+}
+
+/// A process spawned by [`RecordingRunner`]
+struct RecordedProcess {
+    running: Option<Rc<Cell<usize>>>,
+}
+
+impl BackgroundProcess for RecordedProcess {
+    fn is_running(&mut self) -> bool {
+        self.running.is_some()
+    }
+
+    fn kill(&mut self) -> Result<()> {
+        if let Some(running) = self.running.take() {
+            running.set(running.get() - 1);
+        }
+        Ok(())
+    }
+}
+
+fn command_line(command: &CommandSpec) -> String {
+    std::iter::once(command.program.as_str())
+        .chain(command.args.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl CommandRunner for RecordingRunner {
+    fn spawn(&self, command: &CommandSpec, _log: &Path) -> Result<Box<dyn BackgroundProcess>> {
+        let running = if self.run(command)?.success {
+            self.running.set(self.running.get() + 1);
+            Some(self.running.clone())
+        } else {
+            None
+        };
+        Ok(Box::new(RecordedProcess { running }))
+    }
+
+    fn run(&self, command: &CommandSpec) -> Result<CommandOutput> {
+        self.commands.borrow_mut().push(command.clone());
+        let words: Vec<&str> = std::iter::once(command.program.as_str())
+            .chain(command.args.iter().map(String::as_str))
+            .collect();
+        Ok(self
+            .responses
+            .iter()
+            .find(|(prefix, _)| {
+                let prefix: Vec<&str> = prefix.split_whitespace().collect();
+                words.starts_with(&prefix)
+            })
+            .map(|(_, output)| output.clone())
+            .unwrap_or_else(|| CommandOutput::success("")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_display_quotes_arguments_with_spaces() {
+        let command = CommandSpec::new("npm")
+            .args(["pkg", "set", "scripts.test=vitest run"])
+            .current_dir(Path::new("tutorials/my-tutorial"));
+        assert_eq!(
+            command.to_string(),
+            "(cd tutorials/my-tutorial) npm pkg set 'scripts.test=vitest run'"
+        );
+        let command = CommandSpec::new("npx").args(["vitest", "run"]).env("POLKADOT_WS", "ws://127.0.0.1:9945");
+        assert_eq!(command.to_string(), "POLKADOT_WS=ws://127.0.0.1:9945 npx vitest run");
+    }
+
+    #[test]
+    fn test_recording_runner_records_in_order() {
+        let runner = RecordingRunner::new();
+        runner.run(&CommandSpec::new("git").arg("status")).unwrap();
+        runner.run(&CommandSpec::new("npm").arg("test")).unwrap();
+
+        let programs: Vec<_> = runner.commands().into_iter().map(|c| c.program).collect();
+        assert_eq!(programs, vec!["git", "npm"]);
+    }
+
+    #[test]
+    fn test_recording_runner_uses_scripted_responses() {
+        let runner = RecordingRunner::new()
+            .respond("git rev-parse", CommandOutput::success("main\n"))
+            .respond("npm i", CommandOutput::failure(1, "network down"));
+
+        let output = runner
+            .run(&CommandSpec::new("git").args(["rev-parse", "--abbrev-ref", "HEAD"]))
+            .unwrap();
+        assert_eq!(output.stdout, "main\n");
+        assert!(!runner.run(&CommandSpec::new("npm").args(["i", "ws"])).unwrap().success);
+        assert!(runner.run(&CommandSpec::new("npm").arg("init")).unwrap().success);
+        assert_eq!(runner.command_lines(), vec!["git rev-parse --abbrev-ref HEAD", "npm i ws", "npm init"]);
+    }
+
+    #[test]
+    fn test_run_checked_reports_exit_code_and_stderr() {
+        let runner = RecordingRunner::new().respond("npm", CommandOutput::failure(127, "npm: not found\n"));
+        let err = runner
+            .run_checked(&CommandSpec::new("npm").args(["init", "-y"]))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "`npm init -y` failed with exit code 127:\nnpm: not found");
+    }
+
+    #[test]
+    fn test_recording_runner_tracks_spawned_processes() {
+        let runner = RecordingRunner::new().respond("crash", CommandOutput::failure(1, ""));
+        let log = Path::new("node.log");
+        let mut node = runner.spawn(&CommandSpec::new("node"), log).unwrap();
+        assert!(node.is_running());
+        assert!(!runner.spawn(&CommandSpec::new("crash"), log).unwrap().is_running());
+        assert_eq!(runner.running_processes(), 1);
+
+        node.kill().unwrap();
+        assert!(!node.is_running());
+        assert_eq!(runner.running_processes(), 0);
+    }
+
+    #[test]
+    fn test_system_runner_spawns_into_log() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let log = dir.path().join("out.log");
+        let mut process = SystemRunner
+            .spawn(&CommandSpec::new("sh").args(["-c", "echo started; echo oops >&2"]), &log)
+            .unwrap();
+        while process.is_running() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(std::fs::read_to_string(&log).unwrap(), "started\noops\n");
+
+        let mut sleeper = SystemRunner.spawn(&CommandSpec::new("sleep").arg("30"), &log).unwrap();
+        assert!(sleeper.is_running());
+        sleeper.kill().unwrap();
+        assert!(!sleeper.is_running());
+    }
+
+    #[test]
+    fn test_system_runner_captures_output() {
+        let output = SystemRunner
+            .run(&CommandSpec::new("sh").args(["-c", "echo out; echo err >&2; exit 3"]))
+            .unwrap();
+        assert!(!output.success);
+        assert_eq!(output.code, Some(3));
+        assert_eq!(output.stdout, "out\n");
+        assert_eq!(output.stderr, "err\n");
+
+        let output = SystemRunner
+            .run(&CommandSpec::new("sh").args(["-c", "echo $POLKADOT_WS"]).env("POLKADOT_WS", "ws://x"))
+            .unwrap();
+        assert_eq!(output.stdout, "ws://x\n");
+    }
+}
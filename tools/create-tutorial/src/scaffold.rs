@@ -1,55 +1,120 @@
-use anyhow::Result;
+use anyhow::{Context as _, Result};
 use colored::*;
-use std::fs;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::bootstrap;
+use crate::context::Context;
+use crate::filesystem::{FileSystem, MemoryFileSystem};
+use crate::git::{self, GitOptions};
+use crate::health::{self, HealthReport};
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::output::OutputFormat;
+use crate::templates::loader::{self, TemplateLoader, TemplateVars};
+use crate::versions::ResolvedVersions;
+
+/// Copy of the generated files, kept in the tutorial so `upgrade` can tell
+/// template changes apart from the author's edits
+pub const SCAFFOLD_BASE_FILE: &str = ".scaffold.json";
+
+/// Contents of `.scaffold.json`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScaffoldBase {
+    /// create-tutorial version that rendered the files
+    pub version: String,
+    /// Generated contents keyed by path relative to the tutorial directory
+    pub files: BTreeMap<String, String>,
+}
+
+impl ScaffoldBase {
+    pub fn new(files: BTreeMap<String, String>) -> Self {
+        ScaffoldBase {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            files,
+        }
+    }
+
+    /// Reads the tutorial's `.scaffold.json`; tutorials created before it
+    /// existed have none
+    pub fn load(fs: &dyn FileSystem, tutorial_dir: &Path) -> Result<Option<Self>> {
+        let path = tutorial_dir.join(SCAFFOLD_BASE_FILE);
+        if !fs.exists(&path) {
+            return Ok(None);
+        }
+        let content = fs.read_to_string(&path)?;
+        let base = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(base))
+    }
 
-use crate::templates;
-use crate::validator::slug_to_title;
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)? + "\n")
+    }
+}
 
 /// Scaffolds the complete tutorial directory structure
-pub fn scaffold_structure(slug: &str) -> Result<()> {
+pub fn scaffold_structure(ctx: &Context, manifest: &Manifest, versions: &ResolvedVersions) -> Result<()> {
     println!("\n{}", "Step 2/4: Scaffolding tutorial structure...".cyan());
 
-    let tutorial_dir = PathBuf::from("tutorials").join(slug);
+    let slug = manifest.slug.as_str();
+    let tutorial_dir = ctx.tutorial_dir(slug);
+
+    create_directories(ctx, &tutorial_dir, manifest)?;
 
-    create_directories(&tutorial_dir, slug)?;
+    create_files(ctx, &tutorial_dir, manifest, versions)?;
 
-    create_files(&tutorial_dir, slug)?;
+    let base = ScaffoldBase::new(render_files(ctx, manifest, versions)?);
+    ctx.fs.write(&tutorial_dir.join(SCAFFOLD_BASE_FILE), &base.to_json()?)?;
 
-    print_success(slug);
+    print_success(manifest);
 
     Ok(())
 }
 
-fn create_directories(tutorial_dir: &PathBuf, slug: &str) -> Result<()> {
-    fs::create_dir_all(tutorial_dir.join("tests"))?;
-    fs::create_dir_all(tutorial_dir.join("scripts"))?;
-    fs::create_dir_all(tutorial_dir.join(format!("{}-code", slug)))?;
+fn create_directories(ctx: &Context, tutorial_dir: &Path, manifest: &Manifest) -> Result<()> {
+    ctx.fs.create_dir_all(&tutorial_dir.join("tests"))?;
+    ctx.fs.create_dir_all(&tutorial_dir.join("scripts"))?;
+    ctx.fs.create_dir_all(&tutorial_dir.join(manifest.project_dir()))?;
     Ok(())
 }
 
-fn create_files(tutorial_dir: &PathBuf, slug: &str) -> Result<()> {
+fn create_files(
+    ctx: &Context,
+    tutorial_dir: &Path,
+    manifest: &Manifest,
+    versions: &ResolvedVersions,
+) -> Result<()> {
+    let slug = manifest.slug.as_str();
+    let framework = manifest.test_framework();
+    let vars = TemplateVars::new(manifest, versions);
+    let loader = TemplateLoader::new(&ctx.root, &vars.tutorial_type);
+
     // Create justfile
-    fs::write(tutorial_dir.join("justfile"), templates::generate_justfile())?;
+    ctx.fs.write(&tutorial_dir.join("justfile"), &loader.render(loader::JUSTFILE, &vars)?)?;
 
-    // Create example test
-    let test_content = templates::generate_test(slug);
-    fs::write(
-        tutorial_dir.join("tests").join(format!("{}-e2e.test.ts", slug)),
-        test_content,
+    // Create example test, plus the crate around it for Rust frameworks
+    ctx.fs.write(
+        &tutorial_dir.join(framework.test_file(slug)),
+        &loader.render(loader::test_template(framework), &vars)?,
     )?;
+    if framework.is_rust() {
+        ctx.fs.write(&tutorial_dir.join("tests").join("Cargo.toml"), &loader.render(loader::TEST_CRATE, &vars)?)?;
+    }
 
     // Create tutorial.yml
-    let title = slug_to_title(slug);
-    let tutorial_yml_content = templates::generate_tutorial_yml(slug, &title);
-    fs::write(tutorial_dir.join("tutorial.yml"), tutorial_yml_content)?;
+    ctx.fs.write(&tutorial_dir.join("tutorial.yml"), &manifest.to_yaml()?)?;
 
     // Create README.md
-    let readme_content = templates::generate_readme(slug);
-    fs::write(tutorial_dir.join("README.md"), readme_content)?;
+    ctx.fs.write(&tutorial_dir.join("README.md"), &loader.render(loader::README, &vars)?)?;
 
+    // Create rust-toolchain.toml
+    ctx.fs.write(
+        &tutorial_dir.join("rust-toolchain.toml"),
+        &loader.render(loader::RUST_TOOLCHAIN, &vars)?,
+    )?;
     // Create .gitkeep in scripts/
-    fs::write(tutorial_dir.join("scripts").join(".gitkeep"), "")?;
+    ctx.fs.write(&tutorial_dir.join("scripts").join(".gitkeep"), "")?;
 
     // Create .gitignore
     let gitignore_content = r#"node_modules/
@@ -57,39 +122,73 @@ dist/
 *.log
 .DS_Store
 coverage/
+target/
 "#;
-    fs::write(tutorial_dir.join(".gitignore"), gitignore_content)?;
+    ctx.fs.write(&tutorial_dir.join(".gitignore"), gitignore_content)?;
 
     Ok(())
 }
 
-fn print_success(slug: &str) {
+/// What the scaffold and test bootstrap generate for `manifest` today,
+/// keyed by path relative to the tutorial directory. `tutorial.yml` (owned
+/// by the author) and the npm-generated `package.json` are not included.
+pub fn render_files(ctx: &Context, manifest: &Manifest, versions: &ResolvedVersions) -> Result<BTreeMap<String, String>> {
+    let memory = MemoryFileSystem::new();
+    let rendering = Context {
+        root: ctx.root.clone(),
+        fs: &memory,
+        runner: ctx.runner,
+        dry_run: ctx.dry_run,
+    };
+    let tutorial_dir = ctx.tutorial_dir(&manifest.slug);
+    create_files(&rendering, &tutorial_dir, manifest, versions)?;
+    bootstrap::create_config_files(&rendering, &tutorial_dir, manifest.test_framework())?;
+
+    let mut files = BTreeMap::new();
+    for (path, content) in memory.files() {
+        let relative = path.strip_prefix(&tutorial_dir)?;
+        if relative != Path::new(MANIFEST_FILE) {
+            let key: Vec<_> = relative.components().map(|c| c.as_os_str().to_string_lossy()).collect();
+            files.insert(key.join("/"), content);
+        }
+    }
+    Ok(files)
+}
+
+fn print_success(manifest: &Manifest) {
+    let slug = manifest.slug.as_str();
     println!("{}", "✅ Scaffolded folder structure".green());
     println!("{}", format!("  - tutorials/{}/README.md", slug).cyan());
     println!("{}", format!("  - tutorials/{}/tutorial.yml", slug).cyan());
-    println!("{}", format!("  - tutorials/{}/tests/{}-e2e.test.ts", slug, slug).cyan());
-    println!("{}", format!("  - tutorials/{}/{}-code/", slug, slug).cyan());
+    println!("{}", format!("  - tutorials/{}/rust-toolchain.toml", slug).cyan());
+    println!("{}", format!("  - tutorials/{}/{}", slug, manifest.test_framework().test_file(slug)).cyan());
+    if manifest.test_framework().is_rust() {
+        println!("{}", format!("  - tutorials/{}/tests/Cargo.toml", slug).cyan());
+    }
+    println!("{}", format!("  - tutorials/{}/{}/", slug, manifest.project_dir()).cyan());
 }
 
-/// Verifies that all required files were created successfully
-pub fn verify_setup(slug: &str) -> Result<()> {
+/// Runs the health checks on the new tutorial and prints them. Failures
+/// don't undo the creation; the caller decides what they mean.
+pub fn verify_setup(ctx: &Context, slug: &str) -> Result<HealthReport> {
     println!("\n{}", "Step 4/4: Verifying setup...".cyan());
 
-    let tutorial_dir = PathBuf::from("tutorials").join(slug);
-    let package_json = tutorial_dir.join("package.json");
-    let readme = tutorial_dir.join("README.md");
-
-    if package_json.exists() && readme.exists() {
-        println!("{}", "✅ All files created successfully!".green());
-    } else {
-        eprintln!("{}", "⚠️  Some files may be missing. Please check the tutorial directory.".yellow());
+    // package.json and node_modules come from npm, which a dry run never runs
+    if ctx.dry_run {
+        println!("{}", "ℹ️  Skipped in a dry run: files created by commands do not exist yet".cyan());
+        return Ok(HealthReport::default());
     }
 
-    Ok(())
+    let tutorial_dir = ctx.tutorial_dir(slug);
+    let report = health::check_tutorial(ctx.fs, &tutorial_dir);
+    report.print(&tutorial_dir.display().to_string(), OutputFormat::Text)?;
+
+    Ok(report)
 }
 
 /// Prints the success message with next steps
-pub fn print_success_message(slug: &str) {
+pub fn print_success_message(manifest: &Manifest, git: &GitOptions) {
+    let slug = manifest.slug.as_str();
     println!("\n{}", "============================================================".green());
     println!("{}", "🎉 Tutorial created successfully!".green());
     println!("{}", "============================================================\n".green());
@@ -100,21 +199,27 @@ pub fn print_success_message(slug: &str) {
     println!("     tutorials/{}/README.md", slug);
     println!();
     println!("{}", "  2. Add your code implementation:".cyan());
-    println!("     tutorials/{}/{}-code/", slug, slug);
+    println!("     tutorials/{}/{}/", slug, manifest.project_dir());
     println!();
     println!("{}", "  3. Write comprehensive tests:".cyan());
     println!("     tutorials/{}/tests/", slug);
     println!();
     println!("{}", "  4. Run tests to verify:".cyan());
-    println!("     cd tutorials/{} && npm test", slug);
+    println!("     cd tutorials/{} && just run-tests", slug);
     println!();
     println!("{}", "  5. Update tutorial.yml metadata:".cyan());
     println!("     tutorials/{}/tutorial.yml", slug);
     println!();
     println!("{}", "  6. When ready, open a Pull Request:".cyan());
-    println!("     git add -A");
-    println!("     git commit -m \"feat(tutorial): add {}\"", slug);
-    println!("     git push origin feat/tutorial-{}", slug);
+    let branch = git.branch_name(slug);
+    if !git.enabled {
+        println!("     git checkout -b {}", branch);
+    }
+    if !(git.enabled && git.commit) {
+        println!("     git add -A");
+        println!("     git commit -m \"{}\"", git::commit_message(slug));
+    }
+    println!("     git push origin {}", branch);
     println!();
 
     println!("{}", "📚 Need help? Check CONTRIBUTING.md or open an issue!\n".blue());
@@ -122,7 +227,7 @@ pub fn print_success_message(slug: &str) {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::path::PathBuf;
 
     #[test]
     fn test_directory_paths() {
@@ -0,0 +1,421 @@
+use anyhow::{anyhow, Context as _, Result};
+use handlebars::Handlebars;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::manifest::{Manifest, TestFramework, PLACEHOLDER_DESCRIPTION};
+use crate::versions::{ResolvedVersions, TOOLS};
+
+/// Directory (relative to the repository root) holding template overrides
+pub const TEMPLATES_DIR: &str = ".cookbook/templates";
+
+/// Override directory consulted for every tutorial type
+pub const SHARED_TEMPLATES: &str = "default";
+
+pub const JUSTFILE: &str = "justfile";
+pub const README: &str = "README.md";
+pub const E2E_TEST: &str = "e2e.test.ts";
+pub const RUST_TOOLCHAIN: &str = "rust-toolchain.toml";
+pub const PAPI_TEST: &str = "papi.test.ts";
+pub const SUBXT_TEST: &str = "subxt.rs";
+pub const RUNTIME_TEST: &str = "runtime.rs";
+/// `tests/Cargo.toml` of the Rust test frameworks
+pub const TEST_CRATE: &str = "tests.Cargo.toml";
+
+/// Partials compiled into the binary, available to every template as
+/// `{{> name}}`. `node-helpers` holds the REQUIRE_NODE/CI logic and node
+/// polling shared by the TypeScript tests; it expects `net` from
+/// `node:net` to be imported.
+const PARTIALS: &[(&str, &str)] = &[("node-helpers", include_str!("defaults/node-helpers.ts.hbs"))];
+
+/// Templates compiled into the binary, used when the repository does not override them
+const EMBEDDED: &[(&str, &str)] = &[
+    (JUSTFILE, include_str!("defaults/justfile.hbs")),
+    (README, include_str!("defaults/README.md.hbs")),
+    (E2E_TEST, include_str!("defaults/e2e.test.ts.hbs")),
+    (RUST_TOOLCHAIN, include_str!("defaults/rust-toolchain.toml.hbs")),
+    (PAPI_TEST, include_str!("defaults/papi.test.ts.hbs")),
+    (SUBXT_TEST, include_str!("defaults/subxt.rs.hbs")),
+    (RUNTIME_TEST, include_str!("defaults/runtime.rs.hbs")),
+    (TEST_CRATE, include_str!("defaults/tests.Cargo.toml.hbs")),
+];
+
+/// Template of the example test scaffolded for `framework`
+pub fn test_template(framework: TestFramework) -> &'static str {
+    match framework {
+        TestFramework::Vitest => E2E_TEST,
+        TestFramework::Papi => PAPI_TEST,
+        TestFramework::Subxt => SUBXT_TEST,
+        TestFramework::Runtime => RUNTIME_TEST,
+    }
+}
+
+/// Where a template was loaded from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// `.cookbook/templates/<dir>/<name>.hbs` in the repository
+    Override(PathBuf),
+    /// Built into create-tutorial
+    Embedded,
+}
+
+/// Variables available to every template
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateVars {
+    pub slug: String,
+    pub title: String,
+    /// Description from the manifest, unless it is still the placeholder
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub tutorial_type: String,
+    /// `tests.framework` from the manifest (vitest, papi, subxt or runtime)
+    pub test_framework: String,
+    /// Whether the tests need a node; generated tests fail without one in CI
+    pub needs_node: bool,
+    pub project_dir: String,
+    pub relay_chain: Option<String>,
+    pub para_id: Option<u32>,
+    pub versions: VersionVars,
+}
+
+/// `versions` as seen by templates
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionVars {
+    pub rust: String,
+    /// Pinned tools in `versions.yml` order, for `{{#each versions.tools}}`
+    pub tools: Vec<ToolVar>,
+    /// npm package versions keyed by package name
+    pub npm: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolVar {
+    pub key: String,
+    pub env_var: String,
+    pub version: String,
+}
+
+impl TemplateVars {
+    /// Collects the variables for a tutorial from its manifest and effective versions
+    pub fn new(manifest: &Manifest, versions: &ResolvedVersions) -> Self {
+        let spec = manifest.manifest.clone().unwrap_or_default();
+        let network = spec.network.unwrap_or_default();
+
+        TemplateVars {
+            slug: manifest.slug.clone(),
+            title: manifest.name.clone(),
+            description: manifest
+                .description
+                .clone()
+                .filter(|description| description != PLACEHOLDER_DESCRIPTION),
+            tutorial_type: manifest.template().to_string(),
+            test_framework: manifest.test_framework().to_string(),
+            needs_node: manifest.needs_node,
+            project_dir: manifest.project_dir(),
+            relay_chain: network.relay_chain,
+            para_id: network.para_id,
+            versions: VersionVars {
+                rust: versions.rust().to_string(),
+                tools: TOOLS
+                    .iter()
+                    .filter_map(|tool| {
+                        versions.tool(tool.key).map(|version| ToolVar {
+                            key: tool.key.to_string(),
+                            env_var: tool.env_var.to_string(),
+                            version: version.to_string(),
+                        })
+                    })
+                    .collect(),
+                npm: versions.npm.clone(),
+            },
+        }
+    }
+}
+
+/// Renders tutorial files from handlebars templates.
+///
+/// A template is looked up as `.cookbook/templates/<type>/<name>.hbs`, then
+/// `.cookbook/templates/default/<name>.hbs`, and finally falls back to the
+/// copy embedded in the binary.
+pub struct TemplateLoader {
+    engine: Handlebars<'static>,
+    search_dirs: Vec<PathBuf>,
+}
+
+impl TemplateLoader {
+    /// A loader that only knows the embedded templates
+    pub fn embedded() -> Self {
+        let mut engine = Handlebars::new();
+        engine.set_strict_mode(true);
+        engine.register_escape_fn(handlebars::no_escape);
+        for (name, partial) in PARTIALS {
+            engine
+                .register_partial(name, partial)
+                .expect("embedded partials are valid handlebars");
+        }
+        TemplateLoader {
+            engine,
+            search_dirs: Vec::new(),
+        }
+    }
+
+    /// A loader that prefers the repository's overrides for `tutorial_type`
+    pub fn new(root: &Path, tutorial_type: &str) -> Self {
+        let templates_dir = root.join(TEMPLATES_DIR);
+        TemplateLoader {
+            search_dirs: vec![templates_dir.join(tutorial_type), templates_dir.join(SHARED_TEMPLATES)],
+            ..Self::embedded()
+        }
+    }
+
+    /// Where the template `name` would be loaded from
+    pub fn source(&self, name: &str) -> TemplateSource {
+        self.search_dirs
+            .iter()
+            .map(|dir| dir.join(format!("{}.hbs", name)))
+            .find(|path| path.is_file())
+            .map(TemplateSource::Override)
+            .unwrap_or(TemplateSource::Embedded)
+    }
+
+    /// Renders the template `name` with `vars`
+    pub fn render(&self, name: &str, vars: &TemplateVars) -> Result<String> {
+        let (template, origin) = match self.source(name) {
+            TemplateSource::Override(path) => {
+                let template = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                (template, path.display().to_string())
+            }
+            TemplateSource::Embedded => {
+                let template = EMBEDDED
+                    .iter()
+                    .find(|(embedded, _)| *embedded == name)
+                    .map(|(_, template)| template.to_string())
+                    .ok_or_else(|| anyhow!("Unknown template \"{}\"", name))?;
+                (template, format!("embedded {} template", name))
+            }
+        };
+
+        self.engine
+            .render_template(&template, vars)
+            .with_context(|| format!("Failed to render {}", origin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::{ManifestFields, TutorialType};
+    use crate::versions::VersionsFile;
+    use tempfile::TempDir;
+
+    fn vars(tutorial_type: TutorialType) -> TemplateVars {
+        vars_with(tutorial_type, &ResolvedVersions::default())
+    }
+
+    fn vars_with(tutorial_type: TutorialType, versions: &ResolvedVersions) -> TemplateVars {
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", tutorial_type);
+        TemplateVars::new(&manifest, versions)
+    }
+
+    fn render(name: &str, tutorial_type: TutorialType) -> String {
+        TemplateLoader::embedded().render(name, &vars(tutorial_type)).unwrap()
+    }
+
+    fn render_for(name: &str, tutorial_type: TutorialType, framework: TestFramework) -> String {
+        let fields = ManifestFields {
+            tutorial_type: Some(tutorial_type),
+            test_framework: Some(framework.to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+        TemplateLoader::embedded()
+            .render(name, &TemplateVars::new(&manifest, &ResolvedVersions::default()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_justfile_contains_default() {
+        let content = render(JUSTFILE, TutorialType::Sdk);
+        assert!(content.starts_with("export RUST_VERSION := \"1.86\"\n"));
+        assert!(content.contains("default:\n  @just --list\n"));
+        assert!(content.contains("say-hello:"));
+    }
+
+    #[test]
+    fn test_justfile_exports_pinned_versions() {
+        let versions = VersionsFile::from_yaml_str("versions:\n  polkadot_omni_node: \"0.6.0\"\n")
+            .unwrap()
+            .resolve("my-tutorial");
+        let content = TemplateLoader::embedded()
+            .render(JUSTFILE, &vars_with(TutorialType::Sdk, &versions))
+            .unwrap();
+        assert!(content.contains("export OMNI_NODE_VERSION := \"0.6.0\"\n"));
+        assert!(content.contains("export RUST_VERSION := \"1.86\"\n"));
+    }
+
+    #[test]
+    fn test_justfile_recipes_follow_template() {
+        let sdk = render(JUSTFILE, TutorialType::Sdk);
+        assert!(sdk.contains("run-zombienet:"));
+        assert!(sdk.contains("cd ../../kitchensink-parachain && cargo build --release"));
+
+        let contracts = render(JUSTFILE, TutorialType::Contracts);
+        assert!(contracts.contains("cd my-tutorial-code && cargo contract build --release"));
+        assert!(!contracts.contains("run-zombienet:"));
+
+        assert!(render(JUSTFILE, TutorialType::Xcm).contains("export PARA_WS := \"ws://127.0.0.1:9988\""));
+        assert!(render(JUSTFILE, TutorialType::RuntimeUpgrade).contains("build-upgrade:"));
+    }
+
+    #[test]
+    fn test_e2e_test_has_vitest_and_polkadot_api() {
+        let test = render(E2E_TEST, TutorialType::Sdk);
+        assert!(test.contains("describe('my-tutorial e2e'"));
+        assert!(test.contains("import { describe, it, expect } from 'vitest'"));
+        assert!(test.contains("ApiPromise"));
+    }
+
+    #[test]
+    fn test_e2e_test_checks_follow_template() {
+        assert!(render(E2E_TEST, TutorialType::Contracts).contains("api.tx.revive"));
+        let xcm = render(E2E_TEST, TutorialType::Xcm);
+        assert!(xcm.contains("api.tx.polkadotXcm"));
+        assert!(xcm.contains("toBe('1000')"));
+        assert!(xcm.contains("ws://127.0.0.1:9988"));
+        assert!(render(E2E_TEST, TutorialType::RuntimeUpgrade).contains("specVersion"));
+    }
+
+    #[test]
+    fn test_node_tests_fail_when_node_is_required() {
+        let test = render(E2E_TEST, TutorialType::Sdk);
+        assert!(test.contains("const NEEDS_NODE = true;"));
+        assert!(test.contains("process.env.REQUIRE_NODE"));
+        assert!(test.contains("throw new Error("));
+        assert!(test.contains("}, NODE_WAIT_MS + 30_000);"));
+
+        let mut manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        manifest.needs_node = false;
+        let vars = TemplateVars::new(&manifest, &ResolvedVersions::default());
+        let loader = TemplateLoader::embedded();
+        assert!(loader.render(E2E_TEST, &vars).unwrap().contains("const NEEDS_NODE = false;"));
+        assert!(loader.render(PAPI_TEST, &vars).unwrap().contains("const NEEDS_NODE = false;"));
+        let subxt = loader.render(SUBXT_TEST, &vars).unwrap();
+        assert!(subxt.contains("const NEEDS_NODE: bool = false;"));
+        assert!(subxt.contains("wait_for_node(&endpoint, timeout).await"));
+    }
+
+    #[test]
+    fn test_typescript_tests_share_node_helpers() {
+        for test in [
+            render(E2E_TEST, TutorialType::Sdk),
+            render_for(PAPI_TEST, TutorialType::Sdk, TestFramework::Papi),
+        ] {
+            assert_eq!(test.matches("async function waitForNode(").count(), 1);
+            assert!(test.contains("import net from 'node:net'"));
+            assert!(!test.contains("{{>"));
+        }
+    }
+
+    #[test]
+    fn test_papi_test_uses_polkadot_api_client() {
+        let test = render_for(PAPI_TEST, TutorialType::Sdk, TestFramework::Papi);
+        assert!(test.contains("import { createClient } from 'polkadot-api'"));
+        assert!(test.contains("client.getFinalizedBlock()"));
+        assert!(!test.contains("@polkadot/api"));
+        assert!(render_for(PAPI_TEST, TutorialType::Xcm, TestFramework::Papi).contains("toBe(1000)"));
+        assert!(render_for(PAPI_TEST, TutorialType::Contracts, TestFramework::Papi).contains("Revive.DepositPerByte"));
+    }
+
+    #[test]
+    fn test_rust_test_crates() {
+        let subxt = render_for(SUBXT_TEST, TutorialType::Xcm, TestFramework::Subxt);
+        assert!(subxt.contains("OnlineClient::<PolkadotConfig>::from_insecure_url"));
+        assert!(subxt.contains("const DEFAULT_ENDPOINT: &str = \"ws://127.0.0.1:9988\";"));
+        assert!(subxt.contains("Some(1000)"));
+        let cargo = render_for(TEST_CRATE, TutorialType::Xcm, TestFramework::Subxt);
+        assert!(cargo.contains("name = \"my-tutorial-tests\""));
+        assert!(cargo.contains("path = \"my-tutorial-e2e.rs\""));
+        assert!(cargo.contains("subxt = "));
+
+        let runtime = render_for(RUNTIME_TEST, TutorialType::Sdk, TestFramework::Runtime);
+        assert!(runtime.contains("Executive::initialize_block(&header)"));
+        assert!(runtime.contains("TestExternalities::new(storage)"));
+        let cargo = render_for(TEST_CRATE, TutorialType::Sdk, TestFramework::Runtime);
+        assert!(cargo.contains("path = \"my-tutorial-runtime.rs\""));
+        assert!(cargo.contains("parachain-template-runtime = { path = \"../../../kitchensink-parachain/runtime\" }"));
+        assert!(!cargo.contains("subxt"));
+    }
+
+    #[test]
+    fn test_readme_sections() {
+        let readme = render(README, TutorialType::Sdk);
+        assert!(readme.starts_with("# My Tutorial\n"));
+        assert!(readme.contains("cd tutorials/my-tutorial"));
+        assert!(readme.contains("Rust `1.86`"));
+        for section in ["## Prerequisites", "## Network", "## Steps", "## Testing", "## Next Steps"] {
+            assert!(readme.contains(section), "missing {}", section);
+        }
+        assert!(readme.contains("parachain `1000` against the `rococo-local` relay chain"));
+
+        let contracts = render(README, TutorialType::Contracts);
+        assert!(contracts.contains("`cargo-contract`"));
+        assert!(!contracts.contains("## Network"));
+    }
+
+    #[test]
+    fn test_rust_toolchain_uses_resolved_version() {
+        let versions = VersionsFile::from_yaml_str("versions:\n  rust: \"1.88\"\n")
+            .unwrap()
+            .resolve("my-tutorial");
+        let toolchain = TemplateLoader::embedded()
+            .render(RUST_TOOLCHAIN, &vars_with(TutorialType::Sdk, &versions))
+            .unwrap();
+        assert_eq!(
+            toolchain,
+            "[toolchain]\nchannel = \"1.88\"\ncomponents = [\"rust-src\"]\ntargets = [\"wasm32-unknown-unknown\"]\n"
+        );
+    }
+
+    #[test]
+    fn test_repository_overrides_win_over_embedded_defaults() {
+        let temp_dir = TempDir::new().unwrap();
+        let xcm_dir = temp_dir.path().join(TEMPLATES_DIR).join("xcm");
+        let shared_dir = temp_dir.path().join(TEMPLATES_DIR).join(SHARED_TEMPLATES);
+        fs::create_dir_all(&xcm_dir).unwrap();
+        fs::create_dir_all(&shared_dir).unwrap();
+        fs::write(xcm_dir.join("README.md.hbs"), "# {{title}} on {{relay_chain}}\n").unwrap();
+        fs::write(
+            shared_dir.join("justfile.hbs"),
+            "{{#each versions.tools}}{{key}} {{/each}}\n",
+        )
+        .unwrap();
+
+        let loader = TemplateLoader::new(temp_dir.path(), "xcm");
+        let vars = vars(TutorialType::Xcm);
+        assert_eq!(loader.render(README, &vars).unwrap(), "# My Tutorial on rococo-local\n");
+        assert_eq!(
+            loader.render(JUSTFILE, &vars).unwrap(),
+            "rust polkadot_omni_node chain_spec_builder frame_omni_bencher \n"
+        );
+        assert_eq!(loader.source(E2E_TEST), TemplateSource::Embedded);
+
+        let sdk_loader = TemplateLoader::new(temp_dir.path(), "sdk");
+        assert_eq!(sdk_loader.source(README), TemplateSource::Embedded);
+    }
+
+    #[test]
+    fn test_unknown_variable_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join(TEMPLATES_DIR).join(SHARED_TEMPLATES);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("README.md.hbs"), "# {{titel}}\n").unwrap();
+
+        let err = TemplateLoader::new(temp_dir.path(), "sdk")
+            .render(README, &vars(TutorialType::Sdk))
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("README.md.hbs"));
+    }
+}
@@ -1,9 +1,5 @@
-pub mod justfile;
-pub mod readme;
-pub mod test;
+pub mod loader;
 pub mod tutorial_yml;
 
-pub use justfile::generate_justfile;
-pub use readme::generate_readme;
-pub use test::generate_test;
+pub use loader::{TemplateLoader, TemplateSource, TemplateVars};
 pub use tutorial_yml::generate_tutorial_yml;
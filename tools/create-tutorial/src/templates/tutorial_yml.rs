@@ -1,12 +1,10 @@
+use crate::manifest::{Manifest, TutorialType};
+
 /// Generates the tutorial.yml metadata file
-pub fn generate_tutorial_yml(slug: &str, title: &str) -> String {
-    format!(r#"name: {}
-slug: {}
-category: polkadot-sdk-cookbook
-needs_node: true
-description: Replace with a short description.
-type: sdk # or contracts
-"#, title, slug)
+pub fn generate_tutorial_yml(slug: &str, title: &str, tutorial_type: TutorialType) -> String {
+    Manifest::new(slug, title, tutorial_type)
+        .to_yaml()
+        .expect("default manifest always serializes")
 }
 
 #[cfg(test)]
@@ -15,17 +13,30 @@ mod tests {
 
     #[test]
     fn test_tutorial_yml_includes_slug_and_title() {
-        let yml = generate_tutorial_yml("my-tutorial", "My Tutorial");
+        let yml = generate_tutorial_yml("my-tutorial", "My Tutorial", TutorialType::Sdk);
         assert!(yml.contains("name: My Tutorial"));
         assert!(yml.contains("slug: my-tutorial"));
     }
 
     #[test]
     fn test_tutorial_yml_has_required_fields() {
-        let yml = generate_tutorial_yml("test", "Test");
+        let yml = generate_tutorial_yml("test", "Test", TutorialType::Sdk);
         assert!(yml.contains("category:"));
         assert!(yml.contains("needs_node:"));
         assert!(yml.contains("description:"));
         assert!(yml.contains("type:"));
     }
+
+    #[test]
+    fn test_tutorial_yml_records_template_type() {
+        let yml = generate_tutorial_yml("test", "Test", TutorialType::RuntimeUpgrade);
+        assert!(yml.contains("type: runtime-upgrade"));
+    }
+
+    #[test]
+    fn test_tutorial_yml_round_trips() {
+        let yml = generate_tutorial_yml("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        let manifest = Manifest::from_yaml_str(&yml).unwrap();
+        assert_eq!(manifest, Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk));
+    }
 }
@@ -0,0 +1,177 @@
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Repository-level spellings, relative to the repository root
+pub const TITLES_FILE: &str = ".cookbook/titles.yml";
+
+/// Acronyms and brand names of the ecosystem, spelled as they should appear
+const BUILTIN_SPELLINGS: &[&str] = &[
+    "Polkadot SDK",
+    "NFT",
+    "NFTs",
+    "XCM",
+    "EVM",
+    "DOT",
+    "PoV",
+    "SDK",
+    "ink!",
+    "API",
+    "RPC",
+];
+
+/// Short words kept lowercase unless they start or end the title
+const CONNECTORS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "of", "on", "or", "the", "to",
+    "via", "vs", "with",
+];
+
+/// `.cookbook/titles.yml`
+#[derive(Debug, Default, Deserialize)]
+struct TitlesFile {
+    /// Words or phrases spelled exactly like this in generated titles
+    #[serde(default)]
+    words: Vec<String>,
+}
+
+/// Turns slugs into titles, respecting known spellings
+#[derive(Debug, Clone)]
+pub struct TitleDictionary {
+    /// (slug words, spelling), longest phrases first
+    spellings: Vec<(Vec<String>, String)>,
+}
+
+impl TitleDictionary {
+    /// The built-in acronyms and brand names
+    pub fn builtin() -> Self {
+        Self::with_spellings(BUILTIN_SPELLINGS.iter().map(|s| s.to_string()))
+    }
+
+    /// The built-in spellings plus the repository's `.cookbook/titles.yml`,
+    /// whose entries win. A missing file adds nothing.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(TITLES_FILE);
+        if !path.is_file() {
+            return Ok(Self::builtin());
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file: Option<TitlesFile> = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        let repo_words = file.unwrap_or_default().words;
+        Ok(Self::with_spellings(
+            repo_words.into_iter().chain(BUILTIN_SPELLINGS.iter().map(|s| s.to_string())),
+        ))
+    }
+
+    /// Earlier spellings win over later ones for the same words
+    fn with_spellings(spellings: impl Iterator<Item = String>) -> Self {
+        let mut entries: Vec<(Vec<String>, String)> = Vec::new();
+        for spelling in spellings {
+            let key = slug_words(&spelling);
+            if !key.is_empty() && !entries.iter().any(|(existing, _)| *existing == key) {
+                entries.push((key, spelling));
+            }
+        }
+        // Stable sort keeps precedence among phrases of equal length
+        entries.sort_by_key(|(key, _)| std::cmp::Reverse(key.len()));
+        TitleDictionary { spellings: entries }
+    }
+
+    /// Converts a slug to a title (e.g., "add-nft-pallet" -> "Add NFT Pallet",
+    /// "zero-to-hero" -> "Zero to Hero")
+    pub fn title(&self, slug: &str) -> String {
+        let words: Vec<&str> = slug.split('-').filter(|w| !w.is_empty()).collect();
+        let mut title = Vec::new();
+        let mut i = 0;
+
+        while i < words.len() {
+            let known = self.spellings.iter().find(|(key, _)| {
+                words.len() - i >= key.len() && key.iter().zip(&words[i..]).all(|(k, w)| k == w)
+            });
+            if let Some((key, spelling)) = known {
+                title.push(spelling.clone());
+                i += key.len();
+                continue;
+            }
+
+            let word = words[i];
+            let is_inner = i > 0 && i + 1 < words.len();
+            if is_inner && CONNECTORS.contains(&word) {
+                title.push(word.to_string());
+            } else {
+                title.push(capitalize(word));
+            }
+            i += 1;
+        }
+
+        title.join(" ")
+    }
+}
+
+/// The lowercase slug words a spelling matches ("ink!" -> ["ink"])
+fn slug_words(spelling: &str) -> Vec<String> {
+    spelling
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_spellings() {
+        let titles = TitleDictionary::builtin();
+        assert_eq!(titles.title("add-nft-pallet"), "Add NFT Pallet");
+        assert_eq!(titles.title("xcm-and-evm"), "XCM and EVM");
+        assert_eq!(titles.title("pov-size"), "PoV Size");
+        assert_eq!(titles.title("ink-contracts"), "ink! Contracts");
+        assert_eq!(titles.title("intro-to-polkadot-sdk"), "Intro to Polkadot SDK");
+        assert_eq!(titles.title("sdk-basics"), "SDK Basics");
+    }
+
+    #[test]
+    fn test_connectors_are_lowercase_inside_the_title() {
+        let titles = TitleDictionary::builtin();
+        assert_eq!(titles.title("zero-to-hero"), "Zero to Hero");
+        assert_eq!(titles.title("the-art-of-pallets"), "The Art of Pallets");
+        assert_eq!(titles.title("what-to-build-with"), "What to Build With");
+    }
+
+    #[test]
+    fn test_repository_spellings_win() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join(".cookbook")).unwrap();
+        fs::write(
+            temp_dir.path().join(TITLES_FILE),
+            "words:\n  - PolkaVM\n  - Asset Hub\n  - Dot\n",
+        )
+        .unwrap();
+
+        let titles = TitleDictionary::load(temp_dir.path()).unwrap();
+        assert_eq!(titles.title("deploy-to-polkavm"), "Deploy to PolkaVM");
+        assert_eq!(titles.title("asset-hub-nfts"), "Asset Hub NFTs");
+        assert_eq!(titles.title("send-dot"), "Send Dot");
+    }
+
+    #[test]
+    fn test_missing_titles_file_uses_builtins() {
+        let temp_dir = TempDir::new().unwrap();
+        let titles = TitleDictionary::load(temp_dir.path()).unwrap();
+        assert_eq!(titles.title("add-nft-pallet"), "Add NFT Pallet");
+    }
+}
@@ -0,0 +1,363 @@
+use anyhow::{bail, Context as _, Result};
+use colored::*;
+use diffy::{DiffOptions, PatchFormatter};
+use std::path::PathBuf;
+
+use crate::context::Context;
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::scaffold::{self, ScaffoldBase, SCAFFOLD_BASE_FILE};
+use crate::versions::{VersionsFile, VERSIONS_FILE};
+
+/// What `upgrade` does with one generated file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileStatus {
+    /// Already matches the current scaffold
+    UpToDate,
+    /// Edited by the author while the template did not change; kept as is
+    Customized,
+    /// Deleted by the author; not brought back
+    Deleted,
+    /// Missing and new in the scaffold; created
+    Added,
+    /// Unedited since it was generated; replaced with the current scaffold
+    Updated,
+    /// Edited by the author and changed in the template; both sets of
+    /// changes merged
+    Merged,
+    /// Edited by the author in a way that overlaps the template change, or
+    /// there is no record of what was generated; left alone
+    Conflict,
+    /// A conflict resolved by taking the current scaffold (`--accept`)
+    Replaced,
+}
+
+/// One generated file and what `upgrade` makes of it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileUpgrade {
+    /// Path relative to the tutorial directory
+    pub path: String,
+    pub status: FileStatus,
+    /// Contents in the tutorial now; `None` if the file does not exist
+    pub current: Option<String>,
+    /// Contents after the upgrade; for conflicts, the current scaffold's
+    pub updated: String,
+}
+
+impl FileUpgrade {
+    /// Whether applying the plan writes this file
+    pub fn is_written(&self) -> bool {
+        matches!(
+            self.status,
+            FileStatus::Added | FileStatus::Updated | FileStatus::Merged | FileStatus::Replaced
+        )
+    }
+}
+
+/// Every change `upgrade` makes to a tutorial
+#[derive(Debug)]
+pub struct UpgradePlan {
+    pub tutorial_dir: PathBuf,
+    pub files: Vec<FileUpgrade>,
+    /// `.scaffold.json` after the upgrade; conflicting files keep their old base
+    pub base: ScaffoldBase,
+}
+
+impl UpgradePlan {
+    pub fn conflicts(&self) -> impl Iterator<Item = &FileUpgrade> {
+        self.files.iter().filter(|file| file.status == FileStatus::Conflict)
+    }
+
+    /// Resolves every conflict by taking the current scaffold and recording
+    /// it in the new base. Tutorials created before `.scaffold.json`
+    /// existed conflict wherever they differ from today's scaffold; this
+    /// brings them under `upgrade` in one step.
+    pub fn accept_conflicts(&mut self) {
+        for file in self.files.iter_mut().filter(|file| file.status == FileStatus::Conflict) {
+            file.status = FileStatus::Replaced;
+            self.base.files.insert(file.path.clone(), file.updated.clone());
+        }
+    }
+}
+
+/// Compares tutorial `slug` with what the scaffold generates today, using
+/// its `.scaffold.json` as the common ancestor of a three-way merge.
+pub fn plan_upgrade(ctx: &Context, slug: &str) -> Result<UpgradePlan> {
+    let tutorial_dir = ctx.tutorial_dir(slug);
+    let manifest_path = tutorial_dir.join(MANIFEST_FILE);
+    if !ctx.fs.exists(&manifest_path) {
+        bail!("{} does not exist", manifest_path.display());
+    }
+    let manifest = Manifest::from_yaml_str(&ctx.fs.read_to_string(&manifest_path)?)
+        .with_context(|| format!("Failed to parse {}", manifest_path.display()))?;
+    let versions_path = ctx.root.join(VERSIONS_FILE);
+    let versions = VersionsFile::from_yaml_str(&ctx.fs.read_to_string(&versions_path)?)
+        .with_context(|| format!("Failed to parse {}", versions_path.display()))?
+        .resolve(slug);
+    let rendered = scaffold::render_files(ctx, &manifest, &versions)?;
+    let old_base = ScaffoldBase::load(ctx.fs, &tutorial_dir)?;
+
+    let mut files = Vec::new();
+    let mut base = ScaffoldBase::new(rendered.clone());
+    for (path, theirs) in rendered {
+        let file_path = tutorial_dir.join(&path);
+        let current = if ctx.fs.exists(&file_path) {
+            Some(ctx.fs.read_to_string(&file_path)?)
+        } else {
+            None
+        };
+        let ancestor = old_base.as_ref().and_then(|base| base.files.get(&path));
+
+        let (status, updated) = merge(ancestor.map(String::as_str), current.as_deref(), &theirs);
+        if status == FileStatus::Conflict {
+            match ancestor {
+                Some(ancestor) => base.files.insert(path.clone(), ancestor.clone()),
+                None => base.files.remove(&path),
+            };
+        }
+        files.push(FileUpgrade {
+            path,
+            status,
+            current,
+            updated,
+        });
+    }
+
+    Ok(UpgradePlan {
+        tutorial_dir,
+        files,
+        base,
+    })
+}
+
+/// Three-way merge of one file: `ancestor` is what was generated, `ours`
+/// what the tutorial has now and `theirs` what the scaffold generates today
+fn merge(ancestor: Option<&str>, ours: Option<&str>, theirs: &str) -> (FileStatus, String) {
+    match (ancestor, ours) {
+        (_, Some(ours)) if ours == theirs => (FileStatus::UpToDate, theirs.to_string()),
+        (Some(_), None) => (FileStatus::Deleted, theirs.to_string()),
+        (None, None) => (FileStatus::Added, theirs.to_string()),
+        (None, Some(_)) => (FileStatus::Conflict, theirs.to_string()),
+        (Some(ancestor), Some(ours)) if ours == ancestor => (FileStatus::Updated, theirs.to_string()),
+        (Some(ancestor), Some(ours)) if theirs == ancestor => (FileStatus::Customized, ours.to_string()),
+        (Some(ancestor), Some(ours)) => match diffy::merge(ancestor, ours, theirs) {
+            Ok(merged) => (FileStatus::Merged, merged),
+            Err(_) => (FileStatus::Conflict, theirs.to_string()),
+        },
+    }
+}
+
+/// Writes the updated files and the new `.scaffold.json`
+pub fn apply_upgrade(ctx: &Context, plan: &UpgradePlan) -> Result<()> {
+    for file in plan.files.iter().filter(|file| file.is_written()) {
+        let path = plan.tutorial_dir.join(&file.path);
+        if let Some(parent) = path.parent() {
+            ctx.fs.create_dir_all(parent)?;
+        }
+        ctx.fs.write(&path, &file.updated)?;
+    }
+    ctx.fs
+        .write(&plan.tutorial_dir.join(SCAFFOLD_BASE_FILE), &plan.base.to_json()?)
+}
+
+/// Prints a diff for every file that changes or conflicts, then a summary
+pub fn print_plan(plan: &UpgradePlan) {
+    let mut formatter = PatchFormatter::new();
+    if colored::control::SHOULD_COLORIZE.should_colorize() {
+        formatter = formatter.with_color();
+    }
+
+    for file in &plan.files {
+        if !file.is_written() && file.status != FileStatus::Conflict {
+            continue;
+        }
+        let current = file.current.as_deref().unwrap_or_default();
+        let patch = DiffOptions::new()
+            .set_original_filename(format!("a/{}", file.path))
+            .set_modified_filename(format!("b/{}", file.path))
+            .create_patch(current, &file.updated);
+        println!("{}", formatter.fmt_patch(&patch));
+    }
+
+    for file in &plan.files {
+        let line = match file.status {
+            FileStatus::UpToDate => continue,
+            FileStatus::Customized => format!("  - kept {} (customized)", file.path).cyan(),
+            FileStatus::Deleted => format!("  - skipped {} (deleted in the tutorial)", file.path).cyan(),
+            FileStatus::Added => format!("  - added {}", file.path).green(),
+            FileStatus::Updated => format!("  - updated {}", file.path).green(),
+            FileStatus::Merged => format!("  - merged {}", file.path).green(),
+            FileStatus::Conflict => format!("  - conflict in {}", file.path).yellow(),
+            FileStatus::Replaced => format!("  - replaced {} with the scaffold's version", file.path).yellow(),
+        };
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filesystem::{FileSystem, MemoryFileSystem, RealFileSystem};
+    use crate::bootstrap;
+    use crate::manifest::TutorialType;
+    use crate::pipeline::CreateOptions;
+    use crate::runner::RecordingRunner;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn status(plan: &UpgradePlan, path: &str) -> FileStatus {
+        plan.files.iter().find(|file| file.path == path).unwrap().status.clone()
+    }
+
+    /// A tutorial as the scaffold generates it today
+    fn scaffolded(temp_dir: &TempDir) -> PathBuf {
+        fs::write(temp_dir.path().join("versions.yml"), "").unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        let options = CreateOptions {
+            offline: true,
+            ..Default::default()
+        };
+        scaffold::scaffold_structure(&ctx, &manifest, &Default::default()).unwrap();
+        bootstrap::bootstrap_tests(&ctx, &manifest, &options, &Default::default()).unwrap();
+        ctx.tutorial_dir("my-tutorial")
+    }
+
+    #[test]
+    fn test_merge_statuses() {
+        assert_eq!(merge(Some("a\n"), Some("b\n"), "b\n").0, FileStatus::UpToDate);
+        assert_eq!(merge(Some("a\n"), None, "b\n").0, FileStatus::Deleted);
+        assert_eq!(merge(None, None, "b\n"), (FileStatus::Added, "b\n".to_string()));
+        assert_eq!(merge(None, Some("a\n"), "b\n").0, FileStatus::Conflict);
+        assert_eq!(merge(Some("a\n"), Some("a\n"), "b\n"), (FileStatus::Updated, "b\n".to_string()));
+        assert_eq!(merge(Some("a\n"), Some("c\n"), "a\n"), (FileStatus::Customized, "c\n".to_string()));
+        assert_eq!(
+            merge(Some("1\n2\n3\n4\n5\n"), Some("one\n2\n3\n4\n5\n"), "1\n2\n3\n4\nfive\n"),
+            (FileStatus::Merged, "one\n2\n3\n4\nfive\n".to_string())
+        );
+        assert_eq!(merge(Some("a\n"), Some("c\n"), "b\n").0, FileStatus::Conflict);
+    }
+
+    #[test]
+    fn test_fresh_tutorial_is_up_to_date() {
+        let temp_dir = TempDir::new().unwrap();
+        scaffolded(&temp_dir);
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+
+        assert!(plan.files.iter().all(|file| file.status == FileStatus::UpToDate));
+        assert!(plan.files.iter().any(|file| file.path == "vitest.config.ts"));
+        assert!(!plan.files.iter().any(|file| file.path == MANIFEST_FILE));
+    }
+
+    #[test]
+    fn test_plan_reads_through_the_context_filesystem() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new(VERSIONS_FILE), "").unwrap();
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&fs, &runner);
+        let manifest = Manifest::new("my-tutorial", "My Tutorial", TutorialType::Sdk);
+        let options = CreateOptions {
+            offline: true,
+            ..Default::default()
+        };
+        scaffold::scaffold_structure(&ctx, &manifest, &Default::default()).unwrap();
+        bootstrap::bootstrap_tests(&ctx, &manifest, &options, &Default::default()).unwrap();
+
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        assert!(!plan.files.is_empty());
+        assert!(plan.files.iter().all(|file| file.status == FileStatus::UpToDate));
+    }
+
+    #[test]
+    fn test_template_changes_are_merged_into_edited_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let tutorial_dir = scaffolded(&temp_dir);
+
+        // The author added a recipe at the end of the justfile and deleted
+        // the .gitignore...
+        let justfile = tutorial_dir.join("justfile");
+        let mut edited = fs::read_to_string(&justfile).unwrap();
+        edited.push_str("\nmy-recipe:\n  echo mine\n");
+        fs::write(&justfile, &edited).unwrap();
+        fs::remove_file(tutorial_dir.join(".gitignore")).unwrap();
+
+        // ...while the template moved on to another Rust version and a
+        // new vitest config
+        fs::write(temp_dir.path().join("versions.yml"), "versions:\n  rust: \"1.90\"\n").unwrap();
+        let mut base = ScaffoldBase::load(&RealFileSystem, &tutorial_dir).unwrap().unwrap();
+        base.files.insert("vitest.config.ts".to_string(), "// old config\n".to_string());
+        fs::write(tutorial_dir.join(SCAFFOLD_BASE_FILE), base.to_json().unwrap()).unwrap();
+        fs::write(tutorial_dir.join("vitest.config.ts"), "// old config\n").unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        assert_eq!(status(&plan, "justfile"), FileStatus::Merged);
+        assert_eq!(status(&plan, "rust-toolchain.toml"), FileStatus::Updated);
+        assert_eq!(status(&plan, "vitest.config.ts"), FileStatus::Updated);
+        assert_eq!(status(&plan, ".gitignore"), FileStatus::Deleted);
+        assert_eq!(plan.conflicts().count(), 0);
+
+        apply_upgrade(&ctx, &plan).unwrap();
+        let justfile = fs::read_to_string(&justfile).unwrap();
+        assert!(justfile.contains("export RUST_VERSION := \"1.90\""));
+        assert!(justfile.ends_with("my-recipe:\n  echo mine\n"));
+        assert!(fs::read_to_string(tutorial_dir.join("vitest.config.ts")).unwrap().contains("defineConfig"));
+        assert!(!tutorial_dir.join(".gitignore").exists());
+
+        // The new base makes a second upgrade a no-op
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        assert!(plan.files.iter().all(|file| !file.is_written() && file.status != FileStatus::Conflict));
+    }
+
+    #[test]
+    fn test_accept_upgrades_a_tutorial_without_a_base() {
+        let temp_dir = TempDir::new().unwrap();
+        let tutorial_dir = scaffolded(&temp_dir);
+        fs::remove_file(tutorial_dir.join(SCAFFOLD_BASE_FILE)).unwrap();
+        fs::write(tutorial_dir.join("justfile"), "default:\n  @just --list\n").unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let mut plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        plan.accept_conflicts();
+        assert_eq!(status(&plan, "justfile"), FileStatus::Replaced);
+        assert_eq!(plan.conflicts().count(), 0);
+
+        apply_upgrade(&ctx, &plan).unwrap();
+        assert!(fs::read_to_string(tutorial_dir.join("justfile")).unwrap().contains("RUST_VERSION"));
+        let base = ScaffoldBase::load(&RealFileSystem, &tutorial_dir).unwrap().unwrap();
+        assert!(base.files.contains_key("justfile"));
+
+        // From now on the tutorial upgrades like any other
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        assert!(plan.files.iter().all(|file| file.status == FileStatus::UpToDate));
+    }
+
+    #[test]
+    fn test_without_a_base_differences_are_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+        let tutorial_dir = scaffolded(&temp_dir);
+        fs::remove_file(tutorial_dir.join(SCAFFOLD_BASE_FILE)).unwrap();
+        fs::write(tutorial_dir.join("justfile"), "default:\n  @just --list\n").unwrap();
+        fs::remove_file(tutorial_dir.join("tsconfig.json")).unwrap();
+
+        let runner = RecordingRunner::new();
+        let ctx = Context::new(&RealFileSystem, &runner).with_root(temp_dir.path());
+        let plan = plan_upgrade(&ctx, "my-tutorial").unwrap();
+        assert_eq!(status(&plan, "justfile"), FileStatus::Conflict);
+        assert_eq!(status(&plan, "tsconfig.json"), FileStatus::Added);
+        assert!(!plan.base.files.contains_key("justfile"));
+
+        apply_upgrade(&ctx, &plan).unwrap();
+        assert_eq!(
+            fs::read_to_string(tutorial_dir.join("justfile")).unwrap(),
+            "default:\n  @just --list\n"
+        );
+        assert!(tutorial_dir.join("tsconfig.json").is_file());
+    }
+}
@@ -1,6 +1,45 @@
 use anyhow::Result;
+use colored::*;
 use regex::Regex;
-use std::path::Path;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::filesystem::RealFileSystem;
+use crate::manifest::{Manifest, MANIFEST_FILE};
+use crate::output::OutputFormat;
+use crate::title::TitleDictionary;
+use crate::versions::{override_key, RESERVED_KEYS};
+
+/// A single problem found while validating a tutorial
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// File the problem was found in (or the missing path)
+    pub file: PathBuf,
+    /// Dotted path of the offending field, if the problem is field-specific
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(file: &Path, field: Option<&str>, message: impl Into<String>) -> Self {
+        Violation {
+            file: file.to_path_buf(),
+            field: field.map(str::to_string),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}: {}", self.file.display(), field, self.message),
+            None => write!(f, "{}: {}", self.file.display(), self.message),
+        }
+    }
+}
 
 /// Validates that the script is being run from the repository root
 pub fn validate_working_directory() -> Result<()> {
@@ -15,32 +54,149 @@ pub fn validate_working_directory() -> Result<()> {
     Ok(())
 }
 
+/// Checks an existing tutorial directory against the layout produced by
+/// `scaffold::create_files` and validates its manifest
+pub fn validate_tutorial(tutorial_dir: &Path) -> Vec<Violation> {
+    if !tutorial_dir.is_dir() {
+        return vec![Violation::new(tutorial_dir, None, "tutorial directory does not exist")];
+    }
+
+    let mut violations = Vec::new();
+    let slug = tutorial_dir
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+
+    if !is_valid_slug(slug) {
+        violations.push(Violation::new(
+            tutorial_dir,
+            None,
+            format!("directory name \"{}\" is not a valid slug", slug),
+        ));
+    }
+
+    for file in ["justfile", "README.md", MANIFEST_FILE] {
+        let path = tutorial_dir.join(file);
+        if !path.is_file() {
+            violations.push(Violation::new(&path, None, "required file is missing"));
+        }
+    }
+
+    let manifest_path = tutorial_dir.join(MANIFEST_FILE);
+    let manifest = if manifest_path.is_file() {
+        match Manifest::load(&manifest_path) {
+            Ok(manifest) => Some(manifest),
+            Err(err) => {
+                violations.push(Violation::new(&manifest_path, None, format!("{:#}", err)));
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Rust test frameworks keep a crate in tests/ instead of *.test.ts files
+    let framework = manifest.as_ref().map(Manifest::test_framework).unwrap_or_default();
+    let tests_dir = tutorial_dir.join("tests");
+    let extension = if framework.is_rust() { ".rs" } else { ".test.ts" };
+    if !has_test_files(&tests_dir, extension) {
+        violations.push(Violation::new(&tests_dir, None, format!("no *{} files found", extension)));
+    }
+    if framework.is_rust() && !tests_dir.join("Cargo.toml").is_file() {
+        violations.push(Violation::new(&tests_dir.join("Cargo.toml"), None, "required file is missing"));
+    }
+
+    // A declared `manifest.build.project_dir` is checked by the manifest itself
+    let declares_project_dir = manifest
+        .as_ref()
+        .and_then(|m| m.manifest.as_ref())
+        .and_then(|spec| spec.build.as_ref())
+        .is_some_and(|build| build.project_dir.is_some());
+    if !declares_project_dir {
+        let code_dir = tutorial_dir.join(format!("{}-code", slug));
+        if !code_dir.is_dir() {
+            violations.push(Violation::new(&code_dir, None, "code directory is missing"));
+        }
+    }
+
+    if let Some(manifest) = manifest {
+        violations.extend(manifest.validate(&RealFileSystem, tutorial_dir));
+    }
+
+    violations
+}
+
+/// Prints the result of `validate_tutorial` in the requested format
+pub fn print_violations(tutorial_dir: &Path, violations: &[Violation], format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let report = serde_json::json!({
+                "tutorial": tutorial_dir,
+                "valid": violations.is_empty(),
+                "violations": violations,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        OutputFormat::Text if violations.is_empty() => {
+            println!("{}", format!("✅ {} follows the cookbook conventions", tutorial_dir.display()).green());
+        }
+        OutputFormat::Text => {
+            eprintln!("{}", format!("❌ Found {} problem(s) in {}:", violations.len(), tutorial_dir.display()).red());
+            for violation in violations {
+                eprintln!("  - {}", violation);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn has_test_files(tests_dir: &Path, extension: &str) -> bool {
+    fs::read_dir(tests_dir)
+        .map(|entries| {
+            entries.flatten().any(|entry| {
+                entry.file_name().to_string_lossy().ends_with(extension)
+            })
+        })
+        .unwrap_or(false)
+}
+
 /// Validates that the slug follows the correct format:
 /// - lowercase letters and numbers only
 /// - words separated by single dashes
 /// - no leading/trailing dashes
+/// - not one of the reserved `versions.yml` keys
 pub fn is_valid_slug(slug: &str) -> bool {
     let slug_regex = Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap();
-    slug_regex.is_match(slug)
+    slug_regex.is_match(slug) && !is_reserved_slug(slug)
 }
 
-/// Converts a slug to a title (e.g., "my-tutorial" -> "My Tutorial")
+/// Whether the slug's override block would clash with a top-level
+/// `versions.yml` key such as `npm`
+pub fn is_reserved_slug(slug: &str) -> bool {
+    RESERVED_KEYS.contains(&override_key(slug).as_str())
+}
+
+/// Converts a slug to a title with the built-in spellings
+/// (e.g., "add-nft-pallet" -> "Add NFT Pallet"). See [`TitleDictionary`]
+/// for repository-specific spellings.
 pub fn slug_to_title(slug: &str) -> String {
-    slug.split('-')
-        .map(|word| {
-            let mut chars = word.chars();
-            match chars.next() {
-                None => String::new(),
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-            }
-        })
+    TitleDictionary::builtin().title(slug)
+}
+
+/// Suggests a slug for a title (e.g., "Add NFT Pallet!" -> "add-nft-pallet")
+pub fn title_to_slug(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
         .collect::<Vec<_>>()
-        .join(" ")
+        .join("-")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::manifest::ManifestFields;
 
     #[test]
     fn test_valid_slug() {
@@ -60,13 +216,94 @@ mod tests {
         assert!(!is_valid_slug("my-tutorial-")); // trailing dash
         assert!(!is_valid_slug("my tutorial")); // space
         assert!(!is_valid_slug("")); // empty
+        assert!(!is_valid_slug("npm")); // versions.yml key
+        assert!(!is_valid_slug("versions"));
+        assert!(is_reserved_slug("npm") && !is_reserved_slug("npm-basics"));
+    }
+
+    fn scaffolded_tutorial(root: &Path, slug: &str) -> PathBuf {
+        let dir = root.join(slug);
+        fs::create_dir_all(dir.join("tests")).unwrap();
+        fs::create_dir_all(dir.join(format!("{}-code", slug))).unwrap();
+        fs::write(dir.join("justfile"), "").unwrap();
+        fs::write(dir.join("README.md"), "").unwrap();
+        fs::write(dir.join("tests").join(format!("{}-e2e.test.ts", slug)), "").unwrap();
+        fs::write(
+            dir.join(MANIFEST_FILE),
+            crate::templates::generate_tutorial_yml(slug, "My Tutorial", crate::manifest::TutorialType::Sdk),
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_validate_tutorial_accepts_scaffolded_layout() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = scaffolded_tutorial(temp_dir.path(), "my-tutorial");
+        assert_eq!(validate_tutorial(&dir), vec![]);
+    }
+
+    #[test]
+    fn test_validate_tutorial_reports_missing_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = scaffolded_tutorial(temp_dir.path(), "my-tutorial");
+        fs::remove_file(dir.join("README.md")).unwrap();
+        fs::remove_file(dir.join("tests/my-tutorial-e2e.test.ts")).unwrap();
+
+        let violations = validate_tutorial(&dir);
+        let files: Vec<_> = violations.iter().map(|v| v.file.clone()).collect();
+        assert!(files.contains(&dir.join("README.md")));
+        assert!(files.contains(&dir.join("tests")));
+        // The manifest still lists the deleted test file
+        assert!(violations
+            .iter()
+            .any(|v| v.field.as_deref() == Some("manifest.tests.files[0]")));
+    }
+
+    #[test]
+    fn test_validate_tutorial_accepts_rust_test_crate() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = scaffolded_tutorial(temp_dir.path(), "my-tutorial");
+        let fields = ManifestFields {
+            test_framework: Some("subxt".to_string()),
+            ..Default::default()
+        };
+        let manifest = Manifest::from_fields("my-tutorial", &fields).unwrap();
+        fs::write(dir.join(MANIFEST_FILE), manifest.to_yaml().unwrap()).unwrap();
+        fs::remove_file(dir.join("tests/my-tutorial-e2e.test.ts")).unwrap();
+        fs::write(dir.join("tests/my-tutorial-e2e.rs"), "").unwrap();
+
+        let violations = validate_tutorial(&dir);
+        assert_eq!(violations, vec![Violation::new(&dir.join("tests/Cargo.toml"), None, "required file is missing")]);
+
+        fs::write(dir.join("tests/Cargo.toml"), "").unwrap();
+        assert_eq!(validate_tutorial(&dir), vec![]);
+    }
+
+    #[test]
+    fn test_validate_tutorial_reports_unparseable_manifest() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = scaffolded_tutorial(temp_dir.path(), "my-tutorial");
+        fs::write(dir.join(MANIFEST_FILE), "needs_node: [").unwrap();
+
+        let violations = validate_tutorial(&dir);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].file, dir.join(MANIFEST_FILE));
     }
 
     #[test]
     fn test_slug_to_title() {
         assert_eq!(slug_to_title("my-tutorial"), "My Tutorial");
-        assert_eq!(slug_to_title("zero-to-hero"), "Zero To Hero");
-        assert_eq!(slug_to_title("add-nft-pallet"), "Add Nft Pallet");
+        assert_eq!(slug_to_title("zero-to-hero"), "Zero to Hero");
+        assert_eq!(slug_to_title("add-nft-pallet"), "Add NFT Pallet");
         assert_eq!(slug_to_title("test"), "Test");
     }
+
+    #[test]
+    fn test_title_to_slug() {
+        assert_eq!(title_to_slug("Add NFT Pallet"), "add-nft-pallet");
+        assert_eq!(title_to_slug("  Zero to Hero: XCM!  "), "zero-to-hero-xcm");
+        assert_eq!(title_to_slug("ink! v5"), "ink-v5");
+        assert!(is_valid_slug(&title_to_slug("Runtime Upgrades 101")));
+    }
 }
@@ -0,0 +1,291 @@
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::manifest::TestFramework;
+
+/// File name of the repository-wide version pins
+pub const VERSIONS_FILE: &str = "versions.yml";
+
+/// Top-level keys of `versions.yml` that are not tutorial override blocks
+pub const RESERVED_KEYS: &[&str] = &["versions", "npm"];
+
+/// A tool pinned in `versions.yml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tool {
+    /// Key under `versions:` and tutorial override blocks
+    pub key: &'static str,
+    /// Environment variable read by the scripts in `common-scripts/`
+    pub env_var: &'static str,
+    /// Other variable names older tutorial scripts use for the same version
+    pub aliases: &'static [&'static str],
+    /// Version used when `versions.yml` does not pin the tool
+    pub default: &'static str,
+}
+
+/// Tools generated tutorials depend on
+pub const TOOLS: &[Tool] = &[
+    Tool { key: "rust", env_var: "RUST_VERSION", aliases: &[], default: "1.86" },
+    Tool { key: "polkadot_omni_node", env_var: "OMNI_NODE_VERSION", aliases: &[], default: "0.5.0" },
+    Tool {
+        key: "chain_spec_builder",
+        env_var: "CHAIN_SPEC_BUILDER_VERSION",
+        aliases: &["CHAIN_SPEC_VERSION"],
+        default: "10.0.0",
+    },
+    Tool { key: "frame_omni_bencher", env_var: "FRAME_OMNI_BENCHER_VERSION", aliases: &[], default: "0.13.0" },
+];
+
+/// npm packages generated tutorials depend on, as (package, dev dependency).
+/// The dev dependencies are shared; see [`npm_packages`] for which client
+/// library each test framework uses.
+pub const NPM_PACKAGES: &[(&str, bool)] = &[
+    ("vitest", true),
+    ("typescript", true),
+    ("ts-node", true),
+    ("@types/node", true),
+    ("@polkadot/api", false),
+    ("ws", false),
+    ("polkadot-api", false),
+];
+
+/// Versions used for npm packages that `versions.yml` does not pin
+const DEFAULT_NPM_VERSIONS: &[(&str, &str)] = &[
+    ("vitest", "^3.2.4"),
+    ("typescript", "^5.9.2"),
+    ("ts-node", "^10.9.2"),
+    ("@types/node", "^24.3.1"),
+    ("@polkadot/api", "^16.4.6"),
+    ("ws", "^8.18.3"),
+    ("polkadot-api", "^1.19.2"),
+];
+
+/// The npm packages a tutorial using `framework` installs; none for the
+/// Rust test frameworks
+pub fn npm_packages(framework: TestFramework) -> Vec<(&'static str, bool)> {
+    let client: &[&str] = match framework {
+        TestFramework::Vitest => &["@polkadot/api", "ws"],
+        TestFramework::Papi => &["polkadot-api"],
+        TestFramework::Subxt | TestFramework::Runtime => return Vec::new(),
+    };
+    NPM_PACKAGES
+        .iter()
+        .copied()
+        .filter(|(package, dev)| *dev || client.contains(package))
+        .collect()
+}
+
+/// Parsed `versions.yml`
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct VersionsFile {
+    /// Global tool versions (rust, polkadot_omni_node, ...)
+    #[serde(default)]
+    pub versions: BTreeMap<String, String>,
+    /// npm package versions used by generated tutorial tests
+    #[serde(default)]
+    pub npm: BTreeMap<String, String>,
+    /// Tutorial-specific tool overrides, keyed by `override_key(slug)`
+    #[serde(flatten)]
+    pub overrides: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// Where an effective version came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionSource {
+    /// The tutorial's override block
+    Override,
+    /// The global `versions:` block
+    Global,
+    /// Built into create-tutorial
+    Default,
+}
+
+impl VersionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionSource::Override => "override",
+            VersionSource::Global => "global",
+            VersionSource::Default => "default",
+        }
+    }
+}
+
+/// Effective versions for one tutorial after merging global pins,
+/// tutorial overrides and built-in defaults
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedVersions {
+    pub tools: BTreeMap<String, (String, VersionSource)>,
+    pub npm: BTreeMap<String, String>,
+}
+
+impl ResolvedVersions {
+    /// Effective version of a tool key such as `rust`
+    pub fn tool(&self, key: &str) -> Option<&str> {
+        self.tools.get(key).map(|(version, _)| version.as_str())
+    }
+
+    pub fn rust(&self) -> &str {
+        self.tool("rust").unwrap_or(TOOLS[0].default)
+    }
+
+    pub fn npm_version(&self, package: &str) -> &str {
+        self.npm.get(package).map(String::as_str).unwrap_or("latest")
+    }
+}
+
+/// The tool whose version a script variable such as `OMNI_NODE_VERSION` holds
+pub fn tool_for_variable(variable: &str) -> Option<&'static Tool> {
+    TOOLS
+        .iter()
+        .find(|tool| tool.env_var == variable || tool.aliases.contains(&variable))
+}
+
+impl Default for ResolvedVersions {
+    fn default() -> Self {
+        VersionsFile::default().resolve("")
+    }
+}
+
+/// Key of a tutorial's override block in `versions.yml` (e.g. `zero_to_hero`)
+pub fn override_key(slug: &str) -> String {
+    slug.replace('-', "_")
+}
+
+impl VersionsFile {
+    /// Loads `versions.yml` from the repository root. An empty file yields no pins.
+    pub fn load(root: &Path) -> Result<Self> {
+        let path = root.join(VERSIONS_FILE);
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::from_yaml_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    pub fn from_yaml_str(content: &str) -> Result<Self> {
+        let parsed: Option<VersionsFile> = serde_yaml::from_str(content)?;
+        Ok(parsed.unwrap_or_default())
+    }
+
+    /// Merges the global pins with the override block of `slug`; the override wins.
+    /// Tools missing from both fall back to the built-in defaults.
+    pub fn resolve(&self, slug: &str) -> ResolvedVersions {
+        let overrides = self.overrides.get(&override_key(slug));
+        let mut tools = BTreeMap::new();
+
+        for tool in TOOLS {
+            tools.insert(tool.key.to_string(), (tool.default.to_string(), VersionSource::Default));
+        }
+        for (key, version) in &self.versions {
+            tools.insert(key.clone(), (version.clone(), VersionSource::Global));
+        }
+        for (key, version) in overrides.into_iter().flatten() {
+            tools.insert(key.clone(), (version.clone(), VersionSource::Override));
+        }
+
+        let npm = NPM_PACKAGES
+            .iter()
+            .map(|(package, _)| (package.to_string(), self.npm_version(package).to_string()))
+            .chain(self.npm.clone())
+            .collect();
+
+        ResolvedVersions { tools, npm }
+    }
+
+    /// Version of an npm package: the pin from `versions.yml`, else the built-in default
+    pub fn npm_version(&self, package: &str) -> &str {
+        self.npm
+            .get(package)
+            .map(String::as_str)
+            .or_else(|| {
+                DEFAULT_NPM_VERSIONS
+                    .iter()
+                    .find(|(name, _)| *name == package)
+                    .map(|(_, version)| *version)
+            })
+            .unwrap_or("latest")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_repository_versions_file() {
+        let versions = VersionsFile::from_yaml_str(
+            r#"
+versions:
+  rust: "1.86"
+  polkadot_omni_node: "0.5.0"
+npm:
+  vitest: "3.0.0"
+zero_to_hero:
+  rust: "1.86"
+"#,
+        )
+        .unwrap();
+        assert_eq!(versions.versions["rust"], "1.86");
+        assert_eq!(versions.npm_version("vitest"), "3.0.0");
+        assert_eq!(versions.npm_version("ws"), "^8.18.3");
+        assert_eq!(versions.npm_version("left-pad"), "latest");
+    }
+
+    #[test]
+    fn test_resolve_prefers_tutorial_override() {
+        let versions = VersionsFile::from_yaml_str(
+            r#"
+versions:
+  rust: "1.86"
+  polkadot_omni_node: "0.5.0"
+my_tutorial:
+  rust: "1.88"
+"#,
+        )
+        .unwrap();
+
+        let resolved = versions.resolve("my-tutorial");
+        assert_eq!(resolved.tools["rust"], ("1.88".to_string(), VersionSource::Override));
+        assert_eq!(resolved.tools["polkadot_omni_node"], ("0.5.0".to_string(), VersionSource::Global));
+        assert_eq!(resolved.tools["frame_omni_bencher"], ("0.13.0".to_string(), VersionSource::Default));
+        assert_eq!(resolved.rust(), "1.88");
+
+        assert_eq!(versions.resolve("other").rust(), "1.86");
+    }
+
+    #[test]
+    fn test_repository_versions_file_resolves() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let versions = VersionsFile::load(&root).unwrap();
+        assert!(versions.overrides.contains_key("zero_to_hero"));
+        assert!(versions.resolve("zero-to-hero").tool("chain_spec_builder").is_some());
+    }
+
+    #[test]
+    fn test_override_key() {
+        assert_eq!(override_key("zero-to-hero"), "zero_to_hero");
+    }
+
+    #[test]
+    fn test_empty_file_has_no_pins() {
+        let versions = VersionsFile::from_yaml_str("# test versions file").unwrap();
+        assert_eq!(versions, VersionsFile::default());
+    }
+
+    #[test]
+    fn test_npm_packages_per_framework() {
+        let names = |framework| npm_packages(framework).into_iter().map(|(package, _)| package).collect::<Vec<_>>();
+        assert_eq!(names(TestFramework::Vitest), ["vitest", "typescript", "ts-node", "@types/node", "@polkadot/api", "ws"]);
+        assert_eq!(names(TestFramework::Papi), ["vitest", "typescript", "ts-node", "@types/node", "polkadot-api"]);
+        assert!(names(TestFramework::Subxt).is_empty());
+        assert!(names(TestFramework::Runtime).is_empty());
+    }
+
+    #[test]
+    fn test_every_package_has_a_default() {
+        for (package, _) in NPM_PACKAGES {
+            assert_ne!(VersionsFile::default().npm_version(package), "latest");
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use anyhow::{bail, Result};
+use colored::*;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use crate::manifest::{Manifest, ManifestFields, TutorialType, DEFAULT_CATEGORY};
+use crate::validator::{is_reserved_slug, is_valid_slug, title_to_slug};
+
+/// Asks for the tutorial metadata interactively and builds its manifest.
+///
+/// Reads answers line by line from `input` and writes prompts to `output`,
+/// so it can be driven by a terminal or by a script in tests. Invalid
+/// answers are explained and asked again; running out of input is an error.
+pub struct Wizard<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Wizard<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Wizard { input, output }
+    }
+
+    /// Asks for every field `preset` leaves unset; preset values (from
+    /// command-line flags) are used as given. `tutorials_dir` is used to
+    /// reject slugs that are already taken.
+    pub fn run(&mut self, tutorials_dir: &Path, preset: &ManifestFields) -> Result<Manifest> {
+        let mut fields = preset.clone();
+        let text = |answer: &str| Ok(answer.to_string());
+
+        let title = match &fields.title {
+            Some(title) => title.clone(),
+            None => self.ask("Title", None, text)?,
+        };
+        fields.title = Some(title.clone());
+
+        let suggested_slug = title_to_slug(&title);
+        let slug = self.ask("Slug", Some(&suggested_slug), |answer| {
+            if is_reserved_slug(answer) {
+                return Err(format!("\"{}\" is reserved by versions.yml.", answer));
+            }
+            if !is_valid_slug(answer) {
+                return Err("Slug must be lowercase, with words separated by dashes.".to_string());
+            }
+            if tutorials_dir.join(answer).exists() {
+                return Err(format!("Tutorial \"{}\" already exists.", answer));
+            }
+            Ok(answer.to_string())
+        })?;
+
+        if fields.description.is_none() {
+            fields.description = Some(self.ask("Description", None, text)?);
+        }
+
+        let tutorial_type = match fields.tutorial_type {
+            Some(tutorial_type) => tutorial_type,
+            None => {
+                let question = format!(
+                    "Type ({})",
+                    TutorialType::ALL.iter().map(TutorialType::as_str).collect::<Vec<_>>().join(", ")
+                );
+                self.ask(&question, Some(TutorialType::default().as_str()), |answer| {
+                    answer.parse::<TutorialType>().map_err(|err| err.to_string())
+                })?
+            }
+        };
+        fields.tutorial_type = Some(tutorial_type);
+
+        if fields.category.is_none() {
+            fields.category = Some(self.ask("Category", Some(DEFAULT_CATEGORY), text)?);
+        }
+
+        if fields.needs_node.is_none() {
+            fields.needs_node = Some(self.ask("Needs a running node? (y/n)", Some("y"), parse_yes_no)?);
+        }
+
+        if tutorial_type.uses_kitchensink() {
+            if fields.relay_chain.is_none() {
+                fields.relay_chain = Some(self.ask("Relay chain", Some("rococo-local"), text)?);
+            }
+            if fields.para_id.is_none() {
+                fields.para_id = Some(self.ask("Parachain id", Some("1000"), |answer| {
+                    answer
+                        .parse::<u32>()
+                        .map_err(|_| "Parachain id must be a non-negative number.".to_string())
+                })?);
+            }
+        }
+
+        Manifest::from_fields(&slug, &fields)
+    }
+
+    /// Asks `question` until `parse` accepts the answer. An empty answer
+    /// takes `default`; without a default an answer is required.
+    fn ask<T>(
+        &mut self,
+        question: &str,
+        default: Option<&str>,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T> {
+        loop {
+            match default {
+                Some(default) => write!(self.output, "{} {} [{}]: ", "?".cyan(), question, default)?,
+                None => write!(self.output, "{} {}: ", "?".cyan(), question)?,
+            }
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                bail!("Input ended before the wizard finished");
+            }
+
+            let answer = match (line.trim(), default) {
+                ("", Some(default)) => default,
+                ("", None) => {
+                    writeln!(self.output, "{}", "❌ An answer is required.".red())?;
+                    continue;
+                }
+                (answer, _) => answer,
+            };
+
+            match parse(answer) {
+                Ok(value) => return Ok(value),
+                Err(message) => writeln!(self.output, "{}", format!("❌ {}", message).red())?,
+            }
+        }
+    }
+}
+
+fn parse_yes_no(answer: &str) -> Result<bool, String> {
+    match answer.to_ascii_lowercase().as_str() {
+        "y" | "yes" => Ok(true),
+        "n" | "no" => Ok(false),
+        _ => Err("Please answer y or n.".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn run(answers: &str, tutorials_dir: &Path) -> (Result<Manifest>, String) {
+        let mut output = Vec::new();
+        let result = Wizard::new(answers.as_bytes(), &mut output).run(tutorials_dir, &ManifestFields::default());
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_defaults_fill_the_manifest() {
+        let temp_dir = TempDir::new().unwrap();
+        let (manifest, _) = run("Add NFT Pallet\n\nMint NFTs from a pallet.\n\n\n\n\n\n", temp_dir.path());
+        let manifest = manifest.unwrap();
+
+        assert_eq!(manifest.name, "Add NFT Pallet");
+        assert_eq!(manifest.slug, "add-nft-pallet");
+        assert_eq!(manifest.description.as_deref(), Some("Mint NFTs from a pallet."));
+        assert_eq!(manifest.tutorial_type.as_deref(), Some("sdk"));
+        assert_eq!(manifest.category, DEFAULT_CATEGORY);
+        assert!(manifest.needs_node);
+        let network = manifest.manifest.unwrap().network.unwrap();
+        assert_eq!(network.relay_chain.as_deref(), Some("rococo-local"));
+        assert_eq!(network.para_id, Some(1000));
+    }
+
+    #[test]
+    fn test_invalid_answers_are_asked_again() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("taken")).unwrap();
+        let answers = [
+            "",            // title is required
+            "My Tutorial",
+            "Bad Slug",    // invalid slug
+            "taken",       // already exists
+            "my-xcm",
+            "Send assets over XCM.",
+            "evm",         // unknown type
+            "xcm",
+            "cross-chain",
+            "maybe",       // not y/n
+            "n",
+            "paseo-local",
+            "-1",          // not a para id
+            "2000",
+        ]
+        .join("\n");
+
+        let (manifest, output) = run(&answers, temp_dir.path());
+        let manifest = manifest.unwrap();
+
+        assert_eq!(manifest.slug, "my-xcm");
+        assert_eq!(manifest.tutorial_type.as_deref(), Some("xcm"));
+        assert_eq!(manifest.category, "cross-chain");
+        assert!(!manifest.needs_node);
+        assert_eq!(manifest.manifest.unwrap().network.unwrap().para_id, Some(2000));
+
+        assert!(output.contains("An answer is required."));
+        assert!(output.contains("Slug must be lowercase"));
+        assert!(output.contains("Tutorial \"taken\" already exists."));
+        assert!(output.contains("unknown tutorial type \"evm\""));
+        assert!(output.contains("Please answer y or n."));
+        assert!(output.contains("Parachain id must be a non-negative number."));
+    }
+
+    #[test]
+    fn test_contracts_skip_network_questions() {
+        let temp_dir = TempDir::new().unwrap();
+        let (manifest, output) = run("Flipper\nflipper\nA flipper contract.\ncontracts\n\ny\n", temp_dir.path());
+
+        assert!(manifest.unwrap().manifest.unwrap().network.is_none());
+        assert!(!output.contains("Relay chain"));
+    }
+
+    #[test]
+    fn test_preset_fields_are_not_asked() {
+        let temp_dir = TempDir::new().unwrap();
+        let preset = ManifestFields {
+            title: Some("Flipper".to_string()),
+            tutorial_type: Some(TutorialType::Contracts),
+            needs_node: Some(false),
+            ..Default::default()
+        };
+        let mut output = Vec::new();
+        let manifest = Wizard::new("\nA flipper contract.\n\n".as_bytes(), &mut output)
+            .run(temp_dir.path(), &preset)
+            .unwrap();
+
+        assert_eq!(manifest.slug, "flipper");
+        assert!(!manifest.needs_node);
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("Title"));
+        assert!(!output.contains("Type"));
+        assert!(!output.contains("node"));
+    }
+
+    #[test]
+    fn test_running_out_of_input_is_an_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let (manifest, _) = run("My Tutorial\n", temp_dir.path());
+        assert_eq!(
+            manifest.unwrap_err().to_string(),
+            "Input ended before the wizard finished"
+        );
+    }
+}
@@ -4,12 +4,13 @@ use std::path::PathBuf;
 use std::process::Command;
 use tempfile::TempDir;
 
-/// Helper to run the create-tutorial binary in a test directory
+/// Helper to run the create-tutorial binary in a test directory.
+/// Uses `--offline` so the tests need neither npm nor network access.
 fn run_create_tutorial(test_dir: &PathBuf, slug: &str) -> Result<std::process::Output> {
     let binary_path = env!("CARGO_BIN_EXE_create-tutorial");
 
     let output = Command::new(binary_path)
-        .arg(slug)
+        .args([slug, "--offline"])
         .current_dir(test_dir)
         .output()?;
 
@@ -29,17 +30,17 @@ fn setup_test_environment() -> Result<TempDir> {
 
     // Initialize git repo
     Command::new("git")
-        .args(&["init"])
+        .args(["init"])
         .current_dir(temp_path)
         .output()?;
 
     Command::new("git")
-        .args(&["config", "user.name", "Test User"])
+        .args(["config", "user.name", "Test User"])
         .current_dir(temp_path)
         .output()?;
 
     Command::new("git")
-        .args(&["config", "user.email", "test@example.com"])
+        .args(["config", "user.email", "test@example.com"])
         .current_dir(temp_path)
         .output()?;
 
@@ -52,10 +53,8 @@ fn test_creates_tutorial_directory_structure() -> Result<()> {
     let temp_path = temp_dir.path();
 
     let slug = "test-tutorial";
-    let output = run_create_tutorial(&temp_path.to_path_buf(), slug)?;
+    run_create_tutorial(&temp_path.to_path_buf(), slug)?;
 
-    // Check that the command succeeded (or at least ran)
-    // It might fail on npm install in CI, but structure should be created
     let tutorial_dir = temp_path.join("tutorials").join(slug);
 
     // Verify directory structure
@@ -73,7 +72,8 @@ fn test_creates_required_files() -> Result<()> {
     let temp_path = temp_dir.path();
 
     let slug = "test-tutorial";
-    run_create_tutorial(&temp_path.to_path_buf(), slug)?;
+    let output = run_create_tutorial(&temp_path.to_path_buf(), slug)?;
+    assert!(output.status.success(), "creation should succeed");
 
     let tutorial_dir = temp_path.join("tutorials").join(slug);
 
@@ -84,6 +84,63 @@ fn test_creates_required_files() -> Result<()> {
     assert!(tutorial_dir.join(".gitignore").exists(), ".gitignore should exist");
     assert!(tutorial_dir.join("tests").join(format!("{}-e2e.test.ts", slug)).exists(),
         "e2e test file should exist");
+    assert!(tutorial_dir.join("package.json").exists(), "package.json should exist");
+    assert!(tutorial_dir.join("vitest.config.ts").exists(), "vitest.config.ts should exist");
+    assert!(tutorial_dir.join("tsconfig.json").exists(), "tsconfig.json should exist");
+
+    Ok(())
+}
+
+#[test]
+fn test_offline_package_json_uses_versions_yml_pins() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path();
+    fs::write(temp_path.join("versions.yml"), "npm:\n  vitest: \"3.1.0\"\n")?;
+
+    let output = run_create_tutorial(&temp_path.to_path_buf(), "test-tutorial")?;
+    assert!(output.status.success(), "offline creation should succeed without npm");
+
+    let package_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(
+        temp_path.join("tutorials/test-tutorial/package.json"),
+    )?)?;
+    assert_eq!(package_json["name"], "test-tutorial");
+    assert_eq!(package_json["type"], "module");
+    assert_eq!(package_json["devDependencies"]["vitest"], "3.1.0");
+
+    Ok(())
+}
+
+#[test]
+fn test_rust_test_framework_scaffolds_a_test_crate() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_create-tutorial"))
+        .args(["runtime-checks", "--offline", "--test-framework", "runtime"])
+        .current_dir(temp_path)
+        .output()?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let tutorial_dir = temp_path.join("tutorials/runtime-checks");
+    let cargo_toml = fs::read_to_string(tutorial_dir.join("tests/Cargo.toml"))?;
+    assert!(cargo_toml.contains("path = \"runtime-checks-runtime.rs\""));
+    assert!(tutorial_dir.join("tests/runtime-checks-runtime.rs").exists());
+    assert!(!tutorial_dir.join("package.json").exists(), "Rust tests need no npm project");
+    assert!(!tutorial_dir.join("vitest.config.ts").exists());
+
+    let manifest = fs::read_to_string(tutorial_dir.join("tutorial.yml"))?;
+    assert!(manifest.contains("needs_node: false"));
+    assert!(manifest.contains("framework: runtime"));
+    let justfile = fs::read_to_string(tutorial_dir.join("justfile"))?;
+    assert!(justfile.contains("SKIP_WASM_BUILD=1 cargo test --manifest-path tests/Cargo.toml"));
+
+    // Both Rust frameworks pass the repository's own linter
+    let output = run_command(&temp_path.to_path_buf(), &["subxt-checks", "--offline", "--test-framework", "subxt"])?;
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    for slug in ["runtime-checks", "subxt-checks"] {
+        let output = run_command(&temp_path.to_path_buf(), &["validate", slug])?;
+        assert!(output.status.success(), "{}: {}", slug, String::from_utf8_lossy(&output.stderr));
+    }
 
     Ok(())
 }
@@ -99,7 +156,7 @@ fn test_readme_contains_correct_slug() -> Result<()> {
     let tutorial_dir = temp_path.join("tutorials").join(slug);
     let readme_content = fs::read_to_string(tutorial_dir.join("README.md"))?;
 
-    assert!(readme_content.contains("# my-awesome-tutorial"), "README should have correct title");
+    assert!(readme_content.starts_with("# My Awesome Tutorial\n"), "README should have correct title");
     assert!(readme_content.contains("cd tutorials/my-awesome-tutorial"), "README should reference correct path");
 
     Ok(())
@@ -116,7 +173,7 @@ fn test_tutorial_yml_has_correct_metadata() -> Result<()> {
     let tutorial_dir = temp_path.join("tutorials").join(slug);
     let yml_content = fs::read_to_string(tutorial_dir.join("tutorial.yml"))?;
 
-    assert!(yml_content.contains("name: Add Nft Pallet"), "Should have correct title");
+    assert!(yml_content.contains("name: Add NFT Pallet"), "Should have correct title");
     assert!(yml_content.contains("slug: add-nft-pallet"), "Should have correct slug");
     assert!(yml_content.contains("category:"), "Should have category field");
     assert!(yml_content.contains("needs_node:"), "Should have needs_node field");
@@ -124,6 +181,346 @@ fn test_tutorial_yml_has_correct_metadata() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_template_option_generates_matching_files() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_command(&temp_path, &["my-xcm-tutorial", "--offline", "--template", "xcm"])?;
+    assert!(output.status.success(), "Should create an xcm tutorial");
+
+    let tutorial_dir = temp_path.join("tutorials").join("my-xcm-tutorial");
+    let yml_content = fs::read_to_string(tutorial_dir.join("tutorial.yml"))?;
+    assert!(yml_content.contains("type: xcm"), "Should record the template type");
+    assert!(yml_content.contains("para_id: 1000"), "Should have a network section");
+
+    let test_content = fs::read_to_string(tutorial_dir.join("tests/my-xcm-tutorial-e2e.test.ts"))?;
+    assert!(test_content.contains("polkadotXcm"), "Should generate the xcm test");
+
+    let output = run_command(&temp_path, &["other-tutorial", "--template", "evm"])?;
+    assert!(!output.status.success(), "Should reject unknown templates");
+
+    Ok(())
+}
+
+#[test]
+fn test_manifest_flags_are_written_to_tutorial_yml() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_command(
+        &temp_path,
+        &[
+            "add-nft-pallet",
+            "--offline",
+            "--title",
+            "Add NFT Pallet",
+            "--description",
+            "Mint NFTs from a pallet.",
+            "--category",
+            "pallets",
+            "--type",
+            "runtime-upgrade",
+            "--no-node",
+            "--relay-chain",
+            "paseo-local",
+            "--para-id",
+            "2000",
+            "--project-dir",
+            "pallet",
+            "--test-framework",
+            "vitest",
+        ],
+    )?;
+    assert!(output.status.success(), "Should create the tutorial: {}", String::from_utf8_lossy(&output.stderr));
+
+    let tutorial_dir = temp_path.join("tutorials").join("add-nft-pallet");
+    let yml_content = fs::read_to_string(tutorial_dir.join("tutorial.yml"))?;
+    for expected in [
+        "name: Add NFT Pallet",
+        "description: Mint NFTs from a pallet.",
+        "category: pallets",
+        "type: runtime-upgrade",
+        "needs_node: false",
+        "relay_chain: paseo-local",
+        "para_id: 2000",
+        "project_dir: pallet",
+        "framework: vitest",
+    ] {
+        assert!(yml_content.contains(expected), "tutorial.yml should contain {:?}", expected);
+    }
+    assert!(tutorial_dir.join("pallet").is_dir(), "Should create the project dir");
+
+    let output = run_command(&temp_path, &["validate", "add-nft-pallet"])?;
+    assert!(output.status.success(), "Flag-created tutorial should validate");
+
+    let output = run_command(&temp_path, &["other-tutorial", "--needs-node", "--no-node"])?;
+    assert!(!output.status.success(), "Should reject conflicting node flags");
+
+    let output = run_command(&temp_path, &["other-tutorial", "--project-dir", "../outside"])?;
+    assert!(!output.status.success(), "Should reject a project dir outside the tutorial");
+
+    Ok(())
+}
+
+#[test]
+fn test_git_commit_and_clean_tree_options() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+    let git = |args: &[&str]| -> Result<String> {
+        let output = Command::new("git").args(args).current_dir(&temp_path).output()?;
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    git(&["add", "-A"])?;
+    git(&["commit", "-qm", "initial"])?;
+
+    let output = run_command(&temp_path, &["my-tutorial", "--offline", "--branch-prefix", "docs/", "--commit"])?;
+    assert!(output.status.success(), "Should create and commit: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(git(&["symbolic-ref", "--short", "HEAD"])?, "docs/my-tutorial");
+    assert_eq!(git(&["log", "-1", "--format=%s"])?, "feat(tutorial): add my-tutorial");
+    assert_eq!(git(&["status", "--porcelain"])?, "", "Everything should be committed");
+
+    fs::write(temp_path.join("notes.txt"), "wip")?;
+    let output = run_command(&temp_path, &["other-tutorial", "--offline", "--require-clean"])?;
+    assert!(!output.status.success(), "Should refuse a dirty tree");
+    assert!(!temp_path.join("tutorials/other-tutorial").exists());
+
+    let output = run_command(&temp_path, &["other-tutorial", "--offline", "--no-git"])?;
+    assert!(output.status.success(), "Should create without git");
+    assert_eq!(git(&["symbolic-ref", "--short", "HEAD"])?, "docs/my-tutorial", "Should stay on the branch");
+
+    Ok(())
+}
+
+#[test]
+fn test_check_base_before_the_slug_keeps_the_slug() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_command(&temp_path, &["--check-base", "my-tutorial", "--dry-run"])?;
+    assert!(output.status.success(), "Should parse: {}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("$ git fetch origin main"), "bare --check-base should use main");
+    assert!(stdout.contains("$ git checkout -b feat/tutorial-my-tutorial"), "slug should not be taken as the branch");
+
+    let output = run_command(&temp_path, &["--check-base=develop", "my-tutorial", "--dry-run"])?;
+    assert!(String::from_utf8_lossy(&output.stdout).contains("$ git fetch origin develop"));
+
+    Ok(())
+}
+
+#[test]
+fn test_rename_moves_tutorial_and_keeps_it_valid() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+    fs::write(temp_path.join("versions.yml"), "add_nft_pallet:\n  rust: \"1.88\"\n")?;
+    // The branch only exists once it has a commit
+    Command::new("git").args(["commit", "-q", "--allow-empty", "-m", "init"]).current_dir(&temp_path).output()?;
+
+    let output = run_create_tutorial(&temp_path, "add-nft-pallet")?;
+    assert!(output.status.success(), "Should create the tutorial");
+
+    let output = run_command(&temp_path, &["rename", "add-nft-pallet", "mint-nfts"])?;
+    assert!(output.status.success(), "Should rename: {}", String::from_utf8_lossy(&output.stderr));
+
+    let tutorial_dir = temp_path.join("tutorials").join("mint-nfts");
+    assert!(!temp_path.join("tutorials/add-nft-pallet").exists());
+    assert!(tutorial_dir.join("mint-nfts-code").is_dir());
+    assert!(tutorial_dir.join("tests/mint-nfts-e2e.test.ts").is_file());
+    let yml_content = fs::read_to_string(tutorial_dir.join("tutorial.yml"))?;
+    assert!(yml_content.contains("name: Mint NFTs"));
+    assert_eq!(fs::read_to_string(temp_path.join("versions.yml"))?, "mint_nfts:\n  rust: \"1.88\"\n");
+
+    let branch = Command::new("git").args(["branch", "--show-current"]).current_dir(&temp_path).output()?;
+    assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "feat/tutorial-mint-nfts");
+
+    let output = run_command(&temp_path, &["validate", "mint-nfts"])?;
+    assert!(output.status.success(), "Renamed tutorial should validate");
+
+    Ok(())
+}
+
+#[test]
+fn test_remove_archives_tutorial_and_strips_overrides() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+    fs::write(
+        temp_path.join("versions.yml"),
+        "versions:\n  rust: \"1.86\"\nold_tutorial:\n  rust: \"1.88\"\n",
+    )?;
+
+    let output = run_create_tutorial(&temp_path, "old-tutorial")?;
+    assert!(output.status.success(), "Should create the tutorial");
+
+    let output = run_command(&temp_path, &["remove", "old-tutorial", "--archive"])?;
+    assert!(output.status.success(), "Should archive: {}", String::from_utf8_lossy(&output.stderr));
+
+    assert!(!temp_path.join("tutorials/old-tutorial").exists());
+    assert!(temp_path.join("tutorials/_archive/old-tutorial/tutorial.yml").is_file());
+    assert_eq!(fs::read_to_string(temp_path.join("versions.yml"))?, "versions:\n  rust: \"1.86\"\n");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("../../kitchensink-parachain"), "Should report shared paths: {}", stdout);
+
+    let output = run_command(&temp_path, &["list"])?;
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("old-tutorial"));
+
+    Ok(())
+}
+
+#[test]
+fn test_upgrade_merges_template_changes_with_edits() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_create_tutorial(&temp_path, "test-tutorial")?;
+    assert!(output.status.success(), "Should create the tutorial");
+    let tutorial_dir = temp_path.join("tutorials").join("test-tutorial");
+    assert!(tutorial_dir.join(".scaffold.json").is_file(), "Should record the generated files");
+
+    let justfile = tutorial_dir.join("justfile");
+    let mut content = fs::read_to_string(&justfile)?;
+    content.push_str("\nmy-recipe:\n  echo mine\n");
+    fs::write(&justfile, content)?;
+    fs::write(temp_path.join("versions.yml"), "versions:\n  rust: \"1.90\"\n")?;
+
+    let output = run_command(&temp_path, &["upgrade", "test-tutorial", "--dry-run"])?;
+    assert!(output.status.success(), "Dry run should succeed");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("+export RUST_VERSION := \"1.90\""));
+    assert!(!fs::read_to_string(&justfile)?.contains("1.90"), "Dry run must not write files");
+
+    let output = run_command(&temp_path, &["upgrade", "test-tutorial"])?;
+    assert!(output.status.success(), "Should upgrade: {}", String::from_utf8_lossy(&output.stderr));
+    let content = fs::read_to_string(&justfile)?;
+    assert!(content.contains("export RUST_VERSION := \"1.90\""));
+    assert!(content.contains("my-recipe:"));
+    assert!(fs::read_to_string(tutorial_dir.join("rust-toolchain.toml"))?.contains("1.90"));
+
+    // An overlapping edit is reported and left alone
+    fs::write(tutorial_dir.join("rust-toolchain.toml"), "[toolchain]\nchannel = \"nightly\"\n")?;
+    fs::write(temp_path.join("versions.yml"), "versions:\n  rust: \"1.91\"\n")?;
+    let output = run_command(&temp_path, &["upgrade", "test-tutorial"])?;
+    assert!(!output.status.success(), "Conflicts should fail the upgrade");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("rust-toolchain.toml"));
+    assert!(fs::read_to_string(tutorial_dir.join("rust-toolchain.toml"))?.contains("nightly"));
+
+    Ok(())
+}
+
+#[test]
+fn test_check_reports_health_as_json() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_create_tutorial(&temp_path, "test-tutorial")?;
+    assert!(output.status.success(), "Should create the tutorial");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("✅ vitest include"));
+
+    let output = run_command(&temp_path, &["check", "test-tutorial", "--format", "json"])?;
+    assert!(output.status.success(), "Fresh tutorial should be healthy");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(report["healthy"], true);
+    let node_modules = report["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|check| check["name"] == "node_modules")
+        .unwrap();
+    assert_eq!(node_modules["status"], "warn", "--offline doesn't install dependencies");
+
+    let tutorial_dir = temp_path.join("tutorials").join("test-tutorial");
+    fs::write(tutorial_dir.join("package.json"), "{}")?;
+    let output = run_command(&temp_path, &["check", "test-tutorial"])?;
+    assert!(!output.status.success(), "A missing test script should fail the check");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("no \"test\" script"));
+
+    Ok(())
+}
+
+/// Creates a tutorial without a node whose build runs `build` and whose
+/// tests use a framework the runner can't run, so `test` stops at the
+/// tests phase at the latest
+fn create_testable_tutorial(test_dir: &PathBuf, slug: &str, build: &str) -> Result<()> {
+    run_command(test_dir, &[slug, "--offline", "--no-node", "--type", "contracts"])?;
+
+    let manifest_path = test_dir.join("tutorials").join(slug).join("tutorial.yml");
+    let manifest = fs::read_to_string(&manifest_path)?
+        .replace("cargo contract build --release", build)
+        .replace("framework: vitest", "framework: jest");
+    fs::write(&manifest_path, manifest)?;
+    Ok(())
+}
+
+#[test]
+fn test_test_reports_each_phase_and_writes_results() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+    create_testable_tutorial(&temp_path, "phased", "echo built")?;
+
+    let output = run_command(&temp_path, &["test", "phased", "--report-dir", "reports"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(stdout.contains("✅ build"), "{}", stdout);
+    assert!(stdout.contains("⏭️  node"), "{}", stdout);
+    assert!(stdout.contains("❌ tests") && stdout.contains("don't know how to run jest tests"), "{}", stdout);
+
+    let summary: serde_json::Value = serde_json::from_str(&fs::read_to_string(temp_path.join("reports/phased.json"))?)?;
+    assert_eq!(summary["failed_phase"], "tests");
+    assert_eq!(summary["phases"][0]["exit_code"], 0);
+    assert_eq!(summary["phases"][0]["log_tail"], "built");
+    let junit = fs::read_to_string(temp_path.join("reports/phased.xml"))?;
+    assert!(junit.contains("<testsuite name=\"phased\" tests=\"4\" failures=\"1\" errors=\"0\" skipped=\"2\""), "{}", junit);
+
+    Ok(())
+}
+
+#[test]
+fn test_test_all_runs_every_tutorial() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+    create_testable_tutorial(&temp_path, "first", "echo built")?;
+    create_testable_tutorial(&temp_path, "second", "exit 3")?;
+
+    let output = run_command(&temp_path, &["test-all", "--jobs", "2", "--report-dir", "reports"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(stdout.contains("Testing 2 tutorials, 2 at a time"), "{}", stdout);
+    assert!(stderr.contains("2 of 2 tutorials failed: first (tests), second (build)"), "{}", stderr);
+    let second: serde_json::Value = serde_json::from_str(&fs::read_to_string(temp_path.join("reports/second.json"))?)?;
+    assert_eq!(second["phases"][0]["exit_code"], 3);
+    assert!(temp_path.join("reports/first.xml").exists());
+
+    Ok(())
+}
+
+#[test]
+fn test_repository_template_overrides_are_used() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let templates_dir = temp_path.join(".cookbook/templates/default");
+    fs::create_dir_all(&templates_dir)?;
+    fs::write(
+        templates_dir.join("README.md.hbs"),
+        "# {{title}}\n{{#if relay_chain}}Relay: {{relay_chain}}\n{{/if}}",
+    )?;
+
+    let output = run_create_tutorial(&temp_path, "my-tutorial")?;
+    assert!(output.status.success(), "Should create the tutorial");
+
+    let tutorial_dir = temp_path.join("tutorials").join("my-tutorial");
+    let readme = fs::read_to_string(tutorial_dir.join("README.md"))?;
+    assert_eq!(readme, "# My Tutorial\nRelay: rococo-local\n");
+
+    let justfile = fs::read_to_string(tutorial_dir.join("justfile"))?;
+    assert!(justfile.contains("say-hello:"), "Should fall back to the embedded justfile");
+
+    Ok(())
+}
+
 #[test]
 fn test_rejects_invalid_slug_formats() -> Result<()> {
     let temp_dir = setup_test_environment()?;
@@ -141,6 +538,11 @@ fn test_rejects_invalid_slug_formats() -> Result<()> {
     let output = run_create_tutorial(&temp_path.to_path_buf(), "my--tutorial")?;
     assert!(!output.status.success(), "Should reject double dash");
 
+    // Test a slug whose override block would clash with versions.yml's npm pins
+    let output = run_create_tutorial(&temp_path.to_path_buf(), "npm")?;
+    assert!(!output.status.success(), "Should reject reserved slug");
+    assert!(!temp_path.join("tutorials/npm").exists());
+
     Ok(())
 }
 
@@ -167,3 +569,130 @@ fn test_prevents_duplicate_tutorial_creation() -> Result<()> {
 
     Ok(())
 }
+
+/// Helper to run an arbitrary create-tutorial command in a test directory
+fn run_command(test_dir: &PathBuf, args: &[&str]) -> Result<std::process::Output> {
+    let binary_path = env!("CARGO_BIN_EXE_create-tutorial");
+
+    let output = Command::new(binary_path)
+        .args(args)
+        .current_dir(test_dir)
+        .output()?;
+
+    Ok(output)
+}
+
+#[test]
+fn test_validate_accepts_freshly_created_tutorial() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    run_create_tutorial(&temp_path, "test-tutorial")?;
+    let output = run_command(&temp_path, &["validate", "test-tutorial"])?;
+
+    assert!(output.status.success(), "Fresh tutorial should validate: {}",
+        String::from_utf8_lossy(&output.stderr));
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_reports_violations_as_json() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    run_create_tutorial(&temp_path, "test-tutorial")?;
+    fs::remove_file(temp_path.join("tutorials/test-tutorial/README.md"))?;
+
+    let output = run_command(&temp_path, &["validate", "test-tutorial", "--format", "json"])?;
+    assert!(!output.status.success(), "Missing README should fail validation");
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(report["valid"], false);
+    assert!(report["violations"][0]["file"].as_str().unwrap().ends_with("README.md"));
+
+    Ok(())
+}
+
+#[test]
+fn test_list_outputs_json_catalog() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    run_create_tutorial(&temp_path, "test-tutorial")?;
+    let output = run_command(&temp_path, &["list", "--format", "json"])?;
+    assert!(output.status.success(), "list should succeed");
+
+    let catalog: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(catalog[0]["slug"], "test-tutorial");
+    assert_eq!(catalog[0]["type"], "sdk");
+    assert_eq!(catalog[0]["test_framework"], "vitest");
+
+    Ok(())
+}
+
+#[test]
+fn test_dry_run_prints_plan_without_side_effects() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    let output = run_command(&temp_path, &["my-guide", "--dry-run"])?;
+    assert!(output.status.success(), "dry run should succeed");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("tutorials/my-guide/tutorial.yml"), "plan should list files");
+    assert!(stdout.contains("sha256:"), "plan should include content hashes");
+    assert!(stdout.contains("$ git checkout -b feat/tutorial-my-guide"), "plan should list git command");
+    assert!(stdout.contains("npm i -D vitest"), "plan should list npm commands");
+    assert!(!stdout.contains("check(s) failed"), "dry run should not report failed checks");
+    assert!(stdout.contains("Would create branch: feat/tutorial-my-guide"), "dry run should name the branch");
+    assert!(!stdout.contains("Created branch"), "dry run should not claim a branch was created");
+
+    assert!(!temp_path.join("tutorials/my-guide").exists(), "dry run must not write files");
+
+    Ok(())
+}
+
+#[test]
+fn test_versions_yml_override_is_injected() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path();
+    fs::write(
+        temp_path.join("versions.yml"),
+        "versions:\n  rust: \"1.86\"\n  polkadot_omni_node: \"0.5.0\"\ntest_tutorial:\n  rust: \"1.88\"\n",
+    )?;
+
+    run_create_tutorial(&temp_path.to_path_buf(), "test-tutorial")?;
+    let tutorial_dir = temp_path.join("tutorials/test-tutorial");
+
+    let toolchain = fs::read_to_string(tutorial_dir.join("rust-toolchain.toml"))?;
+    assert!(toolchain.contains("channel = \"1.88\""), "override should win for rust");
+
+    let justfile = fs::read_to_string(tutorial_dir.join("justfile"))?;
+    assert!(justfile.contains("export OMNI_NODE_VERSION := \"0.5.0\""), "global pin should be exported");
+
+    let readme = fs::read_to_string(tutorial_dir.join("README.md"))?;
+    assert!(readme.contains("Rust `1.88`"), "README should mention the pinned Rust version");
+
+    Ok(())
+}
+
+#[test]
+fn test_versions_check_detects_drift() -> Result<()> {
+    let temp_dir = setup_test_environment()?;
+    let temp_path = temp_dir.path().to_path_buf();
+
+    run_create_tutorial(&temp_path, "test-tutorial")?;
+    let output = run_command(&temp_path, &["versions", "test-tutorial", "--check"])?;
+    assert!(output.status.success(), "fresh tutorial should have no drift");
+
+    fs::write(temp_path.join("versions.yml"), "versions:\n  rust: \"1.90\"\n")?;
+    let output = run_command(&temp_path, &["versions", "test-tutorial", "--check", "--format", "json"])?;
+    assert!(!output.status.success(), "bumped rust version should be reported as drift");
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(report["tools"]["rust"]["version"], "1.90");
+    assert!(report["drift"].as_array().unwrap().iter().any(|d| d["key"] == "rust"));
+
+    Ok(())
+}